@@ -1,37 +1,170 @@
 use pelican_ui::{
-    Align, Area, Component, Context,
-    Drawable, Image, Layout,
-    OnEvent, SizeRequest
+    Align, Area, Color, Component, Context,
+    Drawable, Event, Image, Layout,
+    OnEvent, SizeRequest, TickEvent,
 };
 
-use crate::elements::{Icon, Text, TextStyle};
-use crate::layout::{Offset, Padding, Row, Size};
+use crate::elements::{Icon, Rectangle, ExpandableText, TextStyle};
+use crate::layout::{Offset, Opt, Padding, Row, Size, Stack};
+use crate::components::{Button, IconButton};
+
+use std::sync::mpsc::{self, Receiver};
 
 /// ## Alert
 ///
-/// Displays a warning message with an icon.  
+/// Displays a status message with an icon.  
 ///  
 /// <img src="https://raw.githubusercontent.com/ramp-stack/pelican_ui_std/main/src/examples/alert.png"
 ///      alt="Alert Example"
 ///      width="400">
 ///
+/// The message wraps onto as many lines as it needs. An alert can optionally have an action
+/// button and a close button. Closing hides the alert in place.
+///
+/// Alerts can be shown as a full-width banner under the [`Header`](crate::Header) with [`Page::set_banner`](crate::Page::set_banner).
+///
 /// ### Example
 /// ```rust
 /// let alert = Alert::new(&mut ctx, "Offline. Check Your Connection.");
+/// let alert = Alert::styled(ctx, AlertStyle::Danger, "Payment failed.")
+///     .action(ctx, "Retry", |ctx: &mut Context| ctx.trigger_event(NavigateEvent(0)))
+///     .closable(ctx);
 /// ```
-#[derive(Debug, Component)]
-pub struct Alert(Row, Image, Text);
-impl OnEvent for Alert {}
+#[derive(Component)]
+pub struct Alert(Stack, Opt<AlertBody>, #[skip] AlertStyle, #[skip] Option<Receiver<()>>);
 
 impl Alert {
+    /// Creates a warning alert.
     pub fn new(ctx: &mut Context, message: &str) -> Self {
-        let color = ctx.theme.colors.status.warning;
-        let font_size = ctx.theme.fonts.size.md;
+        Alert::styled(ctx, AlertStyle::Warning, message)
+    }
 
+    /// Creates an alert with the icon and color of `style`.
+    pub fn styled(ctx: &mut Context, style: AlertStyle, message: &str) -> Self {
         Alert(
+            Stack(Offset::Start, Offset::Start, Size::Fit, Size::Fit, Padding::default()),
+            Opt::new(AlertBody::new(ctx, style, message), true),
+            style,
+            None,
+        )
+    }
+
+    /// Adds an action button after the message.
+    pub fn action(mut self, ctx: &mut Context, label: &str, on_click: impl FnMut(&mut Context) + 'static) -> Self {
+        self.content().3 = Some(Button::ghost(ctx, label, on_click));
+        self
+    }
+
+    /// Adds a close button that hides the alert.
+    pub fn closable(mut self, ctx: &mut Context) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        self.content().4 = Some(IconButton::close(ctx, move |_ctx: &mut Context| {let _ = sender.send(());}));
+        self.3 = Some(receiver);
+        self
+    }
+
+    /// Shows the alert as a full-width banner on the secondary background.
+    pub fn banner(mut self, ctx: &mut Context) -> Self {
+        let background = ctx.theme.colors.background.secondary;
+        let body = self.1.inner();
+        body.1 = Some(Rectangle::new(background, 0.0));
+        body.2.0 = Row::new(8.0, Offset::Center, Size::Fit, Padding(24.0, 12.0, 24.0, 12.0));
+        self
+    }
+
+    /// Returns the style of the alert.
+    pub fn style(&self) -> AlertStyle { self.2 }
+    /// Returns the message of the alert.
+    pub fn message(&mut self) -> &mut ExpandableText { &mut self.content().2 }
+    /// Returns the action button if it exists.
+    pub fn action_button(&mut self) -> &mut Option<Button> { &mut self.content().3 }
+    /// Hides the alert.
+    pub fn dismiss(&mut self) { self.1.display(false); }
+    /// Returns `true` if the alert was closed.
+    pub fn is_dismissed(&self) -> bool { !self.1.is_showing() }
+
+    fn content(&mut self) -> &mut AlertContent { &mut self.1.inner().2 }
+}
+
+impl OnEvent for Alert {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() && self.3.as_ref().map(|r| r.try_recv().is_ok()).unwrap_or(false) {
+            self.dismiss();
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for Alert {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Alert({:?})", self.2)
+    }
+}
+
+#[derive(Debug, Component)]
+struct AlertBody(Stack, Option<Rectangle>, AlertContent);
+impl OnEvent for AlertBody {}
+
+impl AlertBody {
+    fn new(ctx: &mut Context, style: AlertStyle, message: &str) -> Self {
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths.last().map(|w| w.0).unwrap_or(0.0), f32::MAX));
+        let height = Size::custom(move |heights: Vec<(f32, f32)>|heights.last().copied().unwrap_or((0.0, 0.0)));
+        AlertBody(
+            Stack(Offset::Start, Offset::Center, width, height, Padding::default()),
+            None,
+            AlertContent::new(ctx, style, message)
+        )
+    }
+}
+
+#[derive(Debug, Component)]
+struct AlertContent(Row, Image, ExpandableText, Option<Button>, Option<IconButton>);
+impl OnEvent for AlertContent {}
+
+impl AlertContent {
+    fn new(ctx: &mut Context, style: AlertStyle, message: &str) -> Self {
+        let color = style.color(ctx);
+        let font_size = ctx.theme.fonts.size.md;
+
+        AlertContent(
             Row::new(4.0, Offset::Center, Size::Fit, Padding::default()),
-            Icon::new(ctx, "warning", color, 32.0),
-            Text::new(ctx, message, TextStyle::Primary, font_size, Align::Left)
+            Icon::new(ctx, style.icon(), color, 32.0),
+            ExpandableText::new(ctx, message, TextStyle::Primary, font_size, Align::Left, None),
+            None,
+            None
         )
     }
 }
+
+/// The status of an [`Alert`] or [`Toast`](crate::Toast), which picks its icon and color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlertStyle {
+    Info,
+    Success,
+    #[default]
+    Warning,
+    Danger,
+}
+
+impl AlertStyle {
+    /// Returns the theme color of the status.
+    pub fn color(&self, ctx: &mut Context) -> Color {
+        let colors = &ctx.theme.colors;
+        match self {
+            AlertStyle::Info => colors.brand.primary,
+            AlertStyle::Success => colors.status.success,
+            AlertStyle::Warning => colors.status.warning,
+            AlertStyle::Danger => colors.status.danger,
+        }
+    }
+
+    /// Returns the name of the icon of the status.
+    pub fn icon(&self) -> &'static str {
+        match self {
+            AlertStyle::Info => "info",
+            AlertStyle::Success => "checkmark",
+            AlertStyle::Warning => "warning",
+            AlertStyle::Danger => "error",
+        }
+    }
+}
@@ -0,0 +1,414 @@
+use pelican_ui::{
+    Align, Area, Component, Context,
+    Drawable, Event, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+    KeyboardEvent, KeyboardState,
+};
+
+use crate::elements::{Text, TextStyle};
+use crate::events::{InputEditedEvent, TextInputSelect};
+use crate::components::Button;
+use crate::layout::{Column, Offset, Padding, Size};
+
+use super::keypad::{Keypad, KeypadKey};
+use super::text_input::error_text;
+
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// The unit an [`AmountInput`] is entered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmountUnit {
+    /// Bitcoin, with up to 8 decimal places.
+    Btc,
+    /// Satoshis, without decimal places.
+    Sats,
+    /// US dollars, with up to 2 decimal places.
+    Usd,
+}
+
+impl AmountUnit {
+    /// Returns how many decimal places can be entered in this unit.
+    pub fn precision(&self) -> usize {
+        match self {
+            AmountUnit::Btc => 8,
+            AmountUnit::Sats => 0,
+            AmountUnit::Usd => 2,
+        }
+    }
+
+    /// Returns `true` for fiat currencies.
+    pub fn is_fiat(&self) -> bool { *self == AmountUnit::Usd }
+
+    /// Converts `value` in this unit to bitcoin, using `rate` dollars per bitcoin for fiat.
+    pub fn to_btc(&self, value: f64, rate: Option<f64>) -> Option<f64> {
+        match self {
+            AmountUnit::Btc => Some(value),
+            AmountUnit::Sats => Some(value / 100_000_000.0),
+            AmountUnit::Usd => rate.filter(|r| *r > 0.0).map(|r| value / r),
+        }
+    }
+
+    /// Converts `btc` to this unit, using `rate` dollars per bitcoin for fiat.
+    pub fn from_btc(&self, btc: f64, rate: Option<f64>) -> Option<f64> {
+        match self {
+            AmountUnit::Btc => Some(btc),
+            AmountUnit::Sats => Some(btc * 100_000_000.0),
+            AmountUnit::Usd => rate.map(|r| btc * r),
+        }
+    }
+
+    /// Formats an entered amount with this unit, like `$1,250.5` or `21,000 sats`.
+    fn display(&self, entered: &str) -> String {
+        let (whole, fraction) = entered.split_once('.').map(|(w, f)| (w, Some(f))).unwrap_or((entered, None));
+        let whole = if whole.is_empty() {"0".to_string()} else {group(whole)};
+        let amount = fraction.map(|f| format!("{whole}.{f}")).unwrap_or(whole);
+        match self {
+            AmountUnit::Btc => format!("{amount} BTC"),
+            AmountUnit::Sats => format!("{amount} sats"),
+            AmountUnit::Usd => format!("${amount}"),
+        }
+    }
+
+    /// Writes `value` with the precision of this unit, without trailing zeros.
+    fn entered(&self, value: f64) -> String {
+        let value = format!("{:.*}", self.precision(), value);
+        match value.contains('.') {
+            true => value.trim_end_matches('0').trim_end_matches('.').to_string(),
+            false => value,
+        }
+    }
+}
+
+/// ## Amount Input
+///
+/// A large amount above a numeric keypad, for entering how much to send or request.
+///
+/// The amount is entered in an [`AmountUnit`], up to the decimal places of that unit. When an exchange rate
+/// is supplied, the amount is also shown in the other currency, and pressing it switches which one is entered.
+/// An error is shown while the amount is more than the balance set with [`AmountInput::max`].
+///
+/// On desktop and web the amount can be typed as well. Every change triggers an [`InputEditedEvent`].
+///
+/// ### Example
+/// ```rust
+/// let input = AmountInput::new(ctx, AmountUnit::Usd, Some(64_000.0)).max(ctx, 250_000);
+/// let sats = input.sats();
+/// ```
+#[derive(Debug, Component)]
+pub struct AmountInput(Column, AmountDisplay, Keypad, #[skip] AmountState, #[skip] Receiver<KeypadKey>);
+
+impl AmountInput {
+    /// The most digits that can be entered before the decimal point.
+    pub const MAX_DIGITS: usize = 12;
+
+    pub fn new(ctx: &mut Context, unit: AmountUnit, rate: Option<f64>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let state = AmountState {
+            entered: String::new(),
+            unit,
+            other: if unit.is_fiat() {AmountUnit::Btc} else {AmountUnit::Usd},
+            rate,
+            max: None,
+            error: None,
+            focus: true,
+        };
+        let mut input = AmountInput(
+            Column::new(24.0, Offset::Center, Size::fill(), Padding::default()),
+            AmountDisplay::new(ctx, sender.clone()),
+            Keypad::new(ctx, sender, true),
+            state,
+            receiver
+        );
+        input.refresh(ctx);
+        input
+    }
+
+    /// Sets the balance in sats that the amount can't be more than.
+    pub fn max(mut self, ctx: &mut Context, sats: u64) -> Self {
+        self.set_max(ctx, Some(sats));
+        self
+    }
+
+    /// Sets or removes the balance in sats that the amount can't be more than.
+    pub fn set_max(&mut self, ctx: &mut Context, sats: Option<u64>) {
+        self.3.max = sats;
+        self.refresh(ctx);
+    }
+
+    /// Sets the exchange rate in dollars per bitcoin, or hides the other currency with `None`.
+    pub fn set_rate(&mut self, ctx: &mut Context, rate: Option<f64>) {
+        self.3.rate = rate;
+        self.refresh(ctx);
+    }
+
+    /// Sets the unit of the secondary amount shown under the entered amount, e.g. [`AmountUnit::Sats`] instead of bitcoin.
+    pub fn set_secondary_unit(&mut self, ctx: &mut Context, unit: AmountUnit) {
+        self.3.other = unit;
+        self.refresh(ctx);
+    }
+
+    /// Returns the unit the amount is entered in.
+    pub fn unit(&self) -> AmountUnit { self.3.unit }
+    /// Returns the amount as entered, in its [`AmountUnit`].
+    pub fn value(&self) -> f64 { self.3.value() }
+    /// Returns the amount in bitcoin, or `None` for a fiat amount without an exchange rate.
+    pub fn amount(&self) -> Option<f64> { self.3.btc() }
+    /// Returns the amount in sats, or `None` for a fiat amount without an exchange rate.
+    pub fn sats(&self) -> Option<u64> { self.3.sats() }
+
+    /// Returns `true` if the amount is more than zero and no more than the balance.
+    pub fn is_valid(&self) -> bool {
+        self.3.sats().is_some_and(|s| s > 0) && !self.3.exceeds() && self.3.error.is_none()
+    }
+
+    /// Sets the amount in bitcoin, shown in the unit being entered.
+    pub fn set_amount(&mut self, ctx: &mut Context, btc: f64) {
+        let state = &mut self.3;
+        if let Some(value) = state.unit.from_btc(btc, state.rate) { state.entered = state.unit.entered(value); }
+        self.refresh(ctx);
+    }
+
+    /// Shows an error under the amount, like [`TextInput::set_error`](crate::TextInput::set_error).
+    pub fn set_error(&mut self, ctx: &mut Context, error: &str) {
+        self.3.error = Some(error.to_string());
+        self.refresh(ctx);
+    }
+
+    /// Removes the error set with [`AmountInput::set_error`].
+    pub fn clear_error(&mut self, ctx: &mut Context) {
+        self.3.error = None;
+        self.refresh(ctx);
+    }
+
+    /// Switches between entering the amount in bitcoin and in fiat, keeping its value.
+    pub fn toggle_unit(&mut self, ctx: &mut Context) {
+        let state = &mut self.3;
+        let Some(btc) = state.btc() else { return; };
+        let Some(value) = state.other.from_btc(btc, state.rate) else { return; };
+        state.entered = if state.entered.is_empty() {String::new()} else {state.other.entered(value)};
+        std::mem::swap(&mut state.unit, &mut state.other);
+        self.refresh(ctx);
+    }
+
+    fn press(&mut self, ctx: &mut Context, key: KeypadKey) {
+        let changed = match key {
+            KeypadKey::Toggle => {
+                self.toggle_unit(ctx);
+                return;
+            },
+            key => self.3.press(key),
+        };
+        if changed {
+            self.refresh(ctx);
+            ctx.trigger_event(InputEditedEvent);
+        }
+    }
+
+    fn refresh(&mut self, ctx: &mut Context) {
+        let state = &self.3;
+        let secondary = state.rate.and_then(|rate| {
+            let value = state.other.from_btc(state.btc()?, Some(rate))?;
+            Some(format!("≈ {}", state.other.display(&state.other.entered(value))))
+        });
+        let error = match state.exceeds() {
+            true => Some("Amount is more than your balance".to_string()),
+            false => state.error.clone(),
+        };
+        let amount = state.unit.display(&state.entered);
+        let decimals = state.unit.precision() > 0;
+        self.1.set(ctx, &amount, secondary.as_deref(), error.as_deref());
+        self.2.set_decimal(ctx, decimals);
+    }
+}
+
+impl OnEvent for AmountInput {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            let keys = self.4.try_iter().collect::<Vec<_>>();
+            keys.into_iter().for_each(|key| self.press(ctx, key));
+        } else if event.downcast_ref::<TextInputSelect>().is_some() {
+            // Typing goes to the text input that was selected.
+            self.3.focus = false;
+        } else if let Some(MouseEvent{state: MouseState::Pressed, position: Some(_)}) = event.downcast_ref::<MouseEvent>() {
+            self.3.focus = true;
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key}) = event.downcast_ref::<KeyboardEvent>() {
+            if crate::config::IS_MOBILE || !self.3.focus { return true; }
+            if let Some(key) = KeypadKey::from_key(key) { self.press(ctx, key); }
+        }
+        true
+    }
+}
+
+#[derive(Debug)]
+struct AmountState {
+    entered: String,
+    unit: AmountUnit,
+    /// The unit of the secondary amount.
+    other: AmountUnit,
+    /// Dollars per bitcoin.
+    rate: Option<f64>,
+    /// The balance in sats.
+    max: Option<u64>,
+    error: Option<String>,
+    /// Whether typed keys go to this input.
+    focus: bool,
+}
+
+impl AmountState {
+    fn value(&self) -> f64 { self.entered.parse::<f64>().unwrap_or(0.0) }
+    fn btc(&self) -> Option<f64> { self.unit.to_btc(self.value(), self.rate) }
+    fn sats(&self) -> Option<u64> { self.btc().map(|btc| (btc * 100_000_000.0).round() as u64) }
+    fn exceeds(&self) -> bool { self.sats().zip(self.max).is_some_and(|(sats, max)| sats > max) }
+
+    /// Applies a key to the entered amount. Returns `true` if the amount changed.
+    fn press(&mut self, key: KeypadKey) -> bool {
+        let precision = self.unit.precision();
+        let entered = &mut self.entered;
+        match key {
+            KeypadKey::Digit(digit) => match entered.split_once('.') {
+                Some((_, fraction)) if fraction.len() >= precision => false,
+                Some(_) => { entered.push(digit); true },
+                None if entered == "0" => { *entered = digit.to_string(); true },
+                None if entered.len() >= AmountInput::MAX_DIGITS => false,
+                None if digit == '0' && entered.is_empty() && precision == 0 => false,
+                None => { entered.push(digit); true },
+            },
+            KeypadKey::Decimal if precision == 0 || entered.contains('.') => false,
+            KeypadKey::Decimal => {
+                if entered.is_empty() { entered.push('0'); }
+                entered.push('.');
+                true
+            },
+            KeypadKey::Backspace => entered.pop().is_some(),
+            KeypadKey::Toggle => false,
+        }
+    }
+}
+
+/// The entered amount, the amount in the other currency and the error.
+#[derive(Debug, Component)]
+struct AmountDisplay(Column, Text, Option<Button>, Option<Text>, #[skip] Sender<KeypadKey>);
+impl OnEvent for AmountDisplay {}
+
+impl AmountDisplay {
+    fn new(ctx: &mut Context, sender: Sender<KeypadKey>) -> Self {
+        let font_size = ctx.theme.fonts.size.title;
+        AmountDisplay(
+            Column::new(8.0, Offset::Center, Size::Fit, Padding::default()),
+            Text::new(ctx, "", TextStyle::Heading, font_size, Align::Center),
+            None,
+            None,
+            sender
+        )
+    }
+
+    fn set(&mut self, ctx: &mut Context, amount: &str, secondary: Option<&str>, error: Option<&str>) {
+        self.1.text().spans[0].text = amount.to_string();
+
+        match secondary {
+            Some(label) => {
+                if self.2.is_none() {
+                    let sender = self.4.clone();
+                    self.2 = Some(Button::ghost(ctx, label, move |_ctx: &mut Context| {let _ = sender.send(KeypadKey::Toggle);}));
+                }
+                if let Some(text) = self.2.as_mut().and_then(|b| b.label().as_mut()) { text.text().spans[0].text = label.to_string(); }
+            },
+            None => self.2 = None,
+        }
+
+        self.3 = error.map(|error| error_text(ctx, error));
+    }
+}
+
+/// Adds thousands separators to a whole number, like `1,250,000`.
+fn group(whole: &str) -> String {
+    let digits = whole.chars().collect::<Vec<_>>();
+    digits.iter().enumerate().fold(String::new(), |mut grouped, (i, digit)| {
+        if i > 0 && (digits.len() - i) % 3 == 0 { grouped.push(','); }
+        grouped.push(*digit);
+        grouped
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(unit: AmountUnit, entered: &str) -> AmountState {
+        AmountState {
+            entered: entered.to_string(),
+            unit,
+            other: AmountUnit::Btc,
+            rate: None,
+            max: None,
+            error: None,
+            focus: true,
+        }
+    }
+
+    fn type_keys(state: &mut AmountState, keys: &str) {
+        keys.chars().for_each(|key| {
+            state.press(if key == '.' {KeypadKey::Decimal} else {KeypadKey::Digit(key)});
+        });
+    }
+
+    #[test]
+    fn stops_at_unit_precision() {
+        let mut usd = state(AmountUnit::Usd, "");
+        type_keys(&mut usd, "1.234");
+        assert_eq!(usd.entered, "1.23");
+        assert!(!usd.press(KeypadKey::Digit('5')));
+
+        let mut btc = state(AmountUnit::Btc, "");
+        type_keys(&mut btc, "0.123456789");
+        assert_eq!(btc.entered, "0.12345678");
+    }
+
+    #[test]
+    fn replaces_leading_zero() {
+        let mut usd = state(AmountUnit::Usd, "");
+        type_keys(&mut usd, "05");
+        assert_eq!(usd.entered, "5");
+
+        let mut sats = state(AmountUnit::Sats, "");
+        assert!(!sats.press(KeypadKey::Digit('0')));
+        assert_eq!(sats.entered, "");
+    }
+
+    #[test]
+    fn decimal_starts_with_zero() {
+        let mut usd = state(AmountUnit::Usd, "");
+        assert!(usd.press(KeypadKey::Decimal));
+        assert_eq!(usd.entered, "0.");
+        assert!(!usd.press(KeypadKey::Decimal));
+    }
+
+    #[test]
+    fn no_decimal_in_sats() {
+        let mut sats = state(AmountUnit::Sats, "21");
+        assert!(!sats.press(KeypadKey::Decimal));
+        assert_eq!(sats.entered, "21");
+    }
+
+    #[test]
+    fn stops_at_max_digits() {
+        let mut btc = state(AmountUnit::Btc, &"9".repeat(AmountInput::MAX_DIGITS));
+        assert!(!btc.press(KeypadKey::Digit('9')));
+        assert_eq!(btc.entered.len(), AmountInput::MAX_DIGITS);
+        assert!(btc.press(KeypadKey::Decimal));
+        assert!(btc.press(KeypadKey::Digit('5')));
+    }
+
+    #[test]
+    fn backspace_removes_last_key() {
+        let mut usd = state(AmountUnit::Usd, "");
+        type_keys(&mut usd, "1.5");
+        assert!(usd.press(KeypadKey::Backspace));
+        assert_eq!(usd.entered, "1.");
+        assert!(usd.press(KeypadKey::Backspace));
+        assert!(usd.press(KeypadKey::Backspace));
+        assert!(!usd.press(KeypadKey::Backspace));
+        assert_eq!(usd.entered, "");
+    }
+}
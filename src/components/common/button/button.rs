@@ -94,6 +94,8 @@ impl Button {
     pub fn avatar(&mut self) -> &mut Option<Avatar> { &mut self.2.1 }
     /// Returns a mutable reference to the ButtonState.
     pub fn status(&mut self) -> &mut ButtonState {&mut self.4}
+    /// Returns a mutable reference to the button's background.
+    pub fn background(&mut self) -> &mut OutlinedRectangle {&mut self.1}
     /// Returns a mutable reference to the button's optional label.
     pub fn label(&mut self) -> &mut Option<Text> {&mut self.2.3}
     /// Sets the trigger of the on_click to either `On Press` or `On Release`
@@ -0,0 +1,450 @@
+use pelican_ui::{
+    Align, Area, Color, Component, Context,
+    Drawable, Event, Layout, Shape, ShapeType,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+};
+
+use crate::elements::{Text, TextStyle};
+use crate::layout::{Offset, Size};
+
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+type SelectCallback = Box<dyn FnMut(&mut Context, Option<(usize, f32)>)>;
+type LabelFormat = Box<dyn Fn(f32) -> String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChartKind {
+    Line,
+    Area,
+    Bar,
+    Sparkline,
+}
+
+/// ## Chart
+///
+/// Line, area, bar and sparkline charts drawn with shapes.
+///
+/// Labels along the bottom are set with [`Chart::labels`], and the highest and lowest values can be labeled
+/// on the right with [`Chart::y_labels`]. Pressing, dragging or hovering over a chart shows a crosshair on the
+/// nearest value and reports it to [`Chart::on_select`], or `None` once the crosshair goes away.
+///
+/// New values set with [`Chart::set_values`] are animated from the old ones.
+///
+/// ### Example
+/// ```rust
+/// let chart = Chart::area(ctx, balances)
+///     .labels(vec!["Jan".to_string(), "Feb".to_string(), "Mar".to_string()])
+///     .y_labels(|value: f32| format!("${value:.2}"))
+///     .on_select(|ctx: &mut Context, selected: Option<(usize, f32)>| println!("{selected:?}"));
+///
+/// let sparkline = Chart::sparkline(ctx, prices);
+/// let item = ListItem::new(ctx, false, "Bitcoin", None, None, None, None, None, None, None, None, false, |_| {}).trailing(sparkline);
+/// ```
+#[derive(Component)]
+pub struct Chart(ChartLayout, Vec<Shape>, Vec<Text>, Vec<Shape>, #[skip] ChartState);
+
+impl Chart {
+    pub const HEIGHT: f32 = 200.0;
+    pub const SPARKLINE_SIZE: (f32, f32) = (64.0, 24.0);
+    /// Length of the animation between old and new values, in seconds.
+    pub const ANIMATION: f32 = 0.4;
+
+    const STROKE: f32 = 2.5;
+    const COLUMN: f32 = 6.0;
+    const BANDS: usize = 4;
+    const MAX_LABELS: usize = 5;
+
+    /// A line through the values.
+    pub fn line(ctx: &mut Context, values: Vec<f32>) -> Self {
+        Self::new(ctx, ChartKind::Line, values, Size::fill(), Self::HEIGHT)
+    }
+
+    /// A line through the values over a fill fading out towards the bottom.
+    pub fn area(ctx: &mut Context, values: Vec<f32>) -> Self {
+        Self::new(ctx, ChartKind::Area, values, Size::fill(), Self::HEIGHT)
+    }
+
+    /// A bar for each value.
+    pub fn bar(ctx: &mut Context, values: Vec<f32>) -> Self {
+        Self::new(ctx, ChartKind::Bar, values, Size::fill(), Self::HEIGHT)
+    }
+
+    /// A small line without labels or crosshair, e.g. for the end of a [`ListItem`](crate::ListItem).
+    /// It is drawn in the success color when the values went up and in the danger color when they went down.
+    pub fn sparkline(ctx: &mut Context, values: Vec<f32>) -> Self {
+        let colors = &ctx.theme.colors.status;
+        let up = values.first().zip(values.last()).is_none_or(|(first, last)| last >= first);
+        let color = if up {colors.success} else {colors.danger};
+        let (width, height) = Self::SPARKLINE_SIZE;
+        Self::new(ctx, ChartKind::Sparkline, values, Size::Static(width), height).color(color)
+    }
+
+    fn new(ctx: &mut Context, kind: ChartKind, values: Vec<f32>, width: Size, height: f32) -> Self {
+        let state = ChartState {
+            kind,
+            values,
+            from: Vec::new(),
+            animation: None,
+            labels: Vec::new(),
+            y_format: None,
+            color: None,
+            selected: None,
+            on_select: None,
+            pressed: false,
+            drawn: None,
+            points: Vec::new(),
+            plot: (0.0, 0.0),
+        };
+        let mut chart = Chart(ChartLayout(Arc::new(Mutex::new(ChartFrame::default())), width, height), Vec::new(), Vec::new(), Vec::new(), state);
+        chart.draw(ctx);
+        chart
+    }
+
+    /// Sets the height of the chart.
+    pub fn height(mut self, height: f32) -> Self {
+        self.0.2 = height;
+        self
+    }
+
+    /// Sets the color of the chart, the brand color by default.
+    pub fn color(mut self, color: Color) -> Self {
+        self.4.color = Some(color);
+        self.4.drawn = None;
+        self
+    }
+
+    /// Labels along the bottom, spread evenly across the chart. Only a few are shown when there are many.
+    pub fn labels(mut self, labels: Vec<String>) -> Self {
+        self.4.labels = labels;
+        self.4.drawn = None;
+        self
+    }
+
+    /// Labels the highest and lowest values, formatted by `format`.
+    pub fn y_labels(mut self, format: impl Fn(f32) -> String + 'static) -> Self {
+        self.4.y_format = Some(Box::new(format));
+        self.4.drawn = None;
+        self
+    }
+
+    /// Called with the index and value under the crosshair, or `None` once it goes away.
+    pub fn on_select(mut self, on_select: impl FnMut(&mut Context, Option<(usize, f32)>) + 'static) -> Self {
+        self.4.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Returns the values of the chart.
+    pub fn values(&self) -> &[f32] { &self.4.values }
+
+    /// Returns the index under the crosshair.
+    pub fn selected(&self) -> Option<usize> { self.4.selected }
+
+    /// Replaces the values, animating from the old ones.
+    pub fn set_values(&mut self, values: Vec<f32>) {
+        let state = &mut self.4;
+        state.from = resample(&state.current(), values.len());
+        state.values = values;
+        state.animation = Some(Instant::now());
+        state.selected = state.selected.filter(|index| *index < state.values.len());
+        state.drawn = None;
+    }
+
+    fn draw(&mut self, ctx: &mut Context) {
+        let size = self.0.0.lock().unwrap().size;
+        let state = &mut self.4;
+        let values = state.current();
+        let color = state.color.unwrap_or(ctx.theme.colors.brand.primary);
+        let font_size = ctx.theme.fonts.size.xs;
+        let kind = state.kind;
+
+        let bottom = match kind != ChartKind::Sparkline && !state.labels.is_empty() {
+            true => font_size * 1.25 + 8.0,
+            false => 0.0,
+        };
+        let inset = if kind == ChartKind::Sparkline {Self::STROKE} else {8.0};
+        let plot = (inset, (size.1 - bottom - inset).max(inset));
+        let (min, max) = range(&values, kind == ChartKind::Bar);
+        let y_of = |value: f32| plot.1 - (value - min) / (max - min) * (plot.1 - plot.0);
+        let slot = size.0 / values.len().max(1) as f32;
+        let x_of = |index: usize| match kind {
+            ChartKind::Bar => (index as f32 + 0.5) * slot,
+            _ if values.len() < 2 => size.0 / 2.0,
+            _ => index as f32 / (values.len() - 1) as f32 * size.0,
+        };
+        let points = values.iter().enumerate().map(|(i, value)| (x_of(i), y_of(*value))).collect::<Vec<_>>();
+
+        let mut shapes = Vec::new();
+        let mut shape = |shape: ShapeType, color: Color, offset: (f32, f32)| shapes.push((Shape{shape, color}, offset));
+
+        if kind == ChartKind::Area && points.len() > 1 {
+            let band = (plot.1 - plot.0) / Self::BANDS as f32;
+            let mut x = 0.0;
+            while x < size.0 {
+                let y = line_at(&points, x + Self::COLUMN / 2.0);
+                for k in 0..Self::BANDS {
+                    let (top, bottom) = (plot.0 + k as f32 * band, plot.0 + (k + 1) as f32 * band);
+                    let top = top.max(y);
+                    if top >= bottom { continue; }
+                    let alpha = 0.3 * (1.0 - k as f32 / Self::BANDS as f32);
+                    let Color(r, g, b, _) = color;
+                    shape(ShapeType::Rectangle(0.0, (Self::COLUMN, bottom - top), 0.0), Color(r, g, b, (alpha * 255.0) as u8), (x, top));
+                }
+                x += Self::COLUMN;
+            }
+        }
+
+        match kind {
+            ChartKind::Bar => {
+                let width = (slot * 0.75).max(1.0);
+                let zero = y_of(0.0_f32.clamp(min, max));
+                for (x, y) in points.iter() {
+                    let height = (zero - y).abs().max(1.0);
+                    shape(ShapeType::RoundedRectangle(0.0, (width, height), (width / 2.0).min(4.0), 0.0), color, (x - width / 2.0, y.min(zero)));
+                }
+            },
+            _ => {
+                let stroke = if kind == ChartKind::Sparkline {Self::STROKE * 0.6} else {Self::STROKE};
+                points.windows(2).for_each(|segment| {
+                    let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+                    let length = (x1 - x0).hypot(y1 - y0);
+                    let center = ((x0 + x1) / 2.0, (y0 + y1) / 2.0);
+                    // Rotated about its center, so it is placed as if it were level.
+                    let rotation = (y1 - y0).atan2(x1 - x0);
+                    shape(ShapeType::Rectangle(0.0, (length, stroke), rotation), color, (center.0 - length / 2.0, center.1 - stroke / 2.0));
+                });
+                // Rounds off the joints between segments.
+                points.iter().for_each(|(x, y)| shape(ShapeType::Ellipse(0.0, (stroke, stroke), 0.0), color, (x - stroke / 2.0, y - stroke / 2.0)));
+            }
+        }
+
+        let mut labels = Vec::new();
+        if kind != ChartKind::Sparkline {
+            let count = state.labels.len();
+            let shown = (0..count.min(Self::MAX_LABELS)).map(|k| match count > Self::MAX_LABELS {
+                true => k * (count - 1) / (Self::MAX_LABELS - 1),
+                false => k,
+            });
+            for index in shown {
+                let (x, align) = match kind {
+                    ChartKind::Bar => ((index as f32 + 0.5) * size.0 / count as f32, Offset::Center),
+                    _ if index == 0 => (0.0, Offset::Start),
+                    _ if index == count - 1 => (size.0, Offset::End),
+                    _ => (index as f32 / (count - 1) as f32 * size.0, Offset::Center),
+                };
+                labels.push((state.labels[index].clone(), (x, size.1 - bottom + 8.0), (align, Offset::Start)));
+            }
+            if let Some(format) = state.y_format.as_ref().filter(|_| !values.is_empty()) {
+                labels.push((format(max), (size.0, plot.0), (Offset::End, Offset::Start)));
+                labels.push((format(min), (size.0, plot.1), (Offset::End, Offset::End)));
+            }
+        }
+
+        // Labels are kept and only changed when their text does.
+        match self.2.len() == labels.len() {
+            true => self.2.iter_mut().zip(&labels).for_each(|(text, (label, _, _))| {
+                let span = &mut text.text().spans[0];
+                if span.text != *label { span.text = label.clone(); }
+            }),
+            false => self.2 = labels.iter().map(|(label, _, _)| Text::new(ctx, label, TextStyle::Secondary, font_size, Align::Left)).collect(),
+        }
+
+        {
+            let mut frame = self.0.0.lock().unwrap();
+            frame.offsets = shapes.iter().map(|(_, offset)| *offset).collect();
+            frame.labels = labels.into_iter().map(|(_, anchor, align)| (anchor, align)).collect();
+        }
+        self.1 = shapes.into_iter().map(|(shape, _)| shape).collect();
+        self.4.points = points;
+        self.4.plot = plot;
+        self.4.drawn = Some(size);
+        self.place_crosshair(ctx);
+    }
+
+    /// Moves the crosshair to the selected value, or dims the other bars, without drawing the chart again.
+    fn place_crosshair(&mut self, ctx: &mut Context) {
+        let state = &self.4;
+        let color = state.color.unwrap_or(ctx.theme.colors.brand.primary);
+        if state.kind == ChartKind::Bar {
+            let Color(r, g, b, a) = color;
+            self.1.iter_mut().enumerate().for_each(|(index, bar)| {
+                let dimmed = state.selected.is_some_and(|selected| selected != index);
+                bar.color = if dimmed {Color(r, g, b, a / 3)} else {color};
+            });
+            return;
+        }
+
+        let mut frame = self.0.0.lock().unwrap();
+        match state.selected.and_then(|index| state.points.get(index)) {
+            Some((x, y)) => {
+                let (top, bottom) = state.plot;
+                self.3 = vec![
+                    Shape{shape: ShapeType::Rectangle(0.0, (1.0, bottom - top), 0.0), color: ctx.theme.colors.outline.primary},
+                    Shape{shape: ShapeType::Ellipse(0.0, (10.0, 10.0), 0.0), color},
+                ];
+                frame.crosshair = vec![(x - 0.5, top), (x - 5.0, y - 5.0)];
+            },
+            None => {
+                self.3.clear();
+                frame.crosshair.clear();
+            }
+        }
+    }
+
+    /// Moves the crosshair to the value nearest to `x`, or removes it.
+    fn select(&mut self, ctx: &mut Context, x: Option<f32>) {
+        let width = self.0.0.lock().unwrap().size.0;
+        let state = &mut self.4;
+        let count = state.values.len();
+        let selected = x.filter(|_| count > 0 && width > 0.0 && state.kind != ChartKind::Sparkline).map(|x| {
+            let position = (x / width).clamp(0.0, 1.0);
+            match state.kind {
+                ChartKind::Bar => ((position * count as f32) as usize).min(count - 1),
+                _ => (position * (count - 1) as f32).round() as usize,
+            }
+        });
+        if selected == state.selected { return; }
+        state.selected = selected;
+        let value = selected.map(|index| (index, state.values[index]));
+        if let Some(on_select) = state.on_select.as_mut() { on_select(ctx, value); }
+        self.place_crosshair(ctx);
+    }
+}
+
+impl OnEvent for Chart {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            let size = self.0.0.lock().unwrap().size;
+            if self.4.animation.is_some() || self.4.drawn != Some(size) {
+                self.draw(ctx);
+            }
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            match event {
+                MouseEvent{state: MouseState::Pressed, position: Some((x, _))} => {
+                    self.4.pressed = true;
+                    self.select(ctx, Some(*x));
+                },
+                MouseEvent{state: MouseState::Moved | MouseState::Scroll(..), position: Some((x, _))} if self.4.pressed || !crate::config::IS_MOBILE => {
+                    self.select(ctx, Some(*x));
+                },
+                MouseEvent{state: MouseState::Released, ..} => {
+                    self.4.pressed = false;
+                    if crate::config::IS_MOBILE { self.select(ctx, None); }
+                },
+                MouseEvent{state: MouseState::Moved, position: None} if !self.4.pressed => self.select(ctx, None),
+                _ => {}
+            }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for Chart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Chart({:?}, {} values)", self.4.kind, self.4.values.len())
+    }
+}
+
+struct ChartState {
+    kind: ChartKind,
+    values: Vec<f32>,
+    /// The values the animation started from, resampled to the length of `values`.
+    from: Vec<f32>,
+    animation: Option<Instant>,
+    labels: Vec<String>,
+    y_format: Option<LabelFormat>,
+    color: Option<Color>,
+    selected: Option<usize>,
+    on_select: Option<SelectCallback>,
+    pressed: bool,
+    /// The size the shapes were last drawn for.
+    drawn: Option<(f32, f32)>,
+    /// Where each value was last drawn.
+    points: Vec<(f32, f32)>,
+    /// The top and bottom of the plotted values.
+    plot: (f32, f32),
+}
+
+impl ChartState {
+    /// Returns the values as currently shown, part way through the animation.
+    fn current(&mut self) -> Vec<f32> {
+        let duration = if crate::config::reduce_motion() {0.0} else {Chart::ANIMATION};
+        let progress = self.animation.map(|start| start.elapsed().as_secs_f32() / duration).unwrap_or(1.0);
+        if progress >= 1.0 || progress.is_nan() {
+            self.animation = None;
+            return self.values.clone();
+        }
+        let eased = 1.0 - (1.0 - progress).powi(3);
+        self.values.iter().zip(&self.from).map(|(to, from)| from + (to - from) * eased).collect()
+    }
+}
+
+/// Returns `values` stretched or squeezed to `count` values.
+fn resample(values: &[f32], count: usize) -> Vec<f32> {
+    match values.len() {
+        0 => vec![0.0; count],
+        1 => vec![values[0]; count],
+        len => (0..count).map(|i| {
+            let position = if count < 2 {0.0} else {i as f32 / (count - 1) as f32 * (len - 1) as f32};
+            let (index, t) = (position.floor() as usize, position.fract());
+            let next = values[(index + 1).min(len - 1)];
+            values[index] + (next - values[index]) * t
+        }).collect()
+    }
+}
+
+/// Returns the lowest and highest values to plot, including zero for bars.
+fn range(values: &[f32], zero: bool) -> (f32, f32) {
+    let (min, max) = values.iter().fold((f32::MAX, f32::MIN), |(min, max), value| (min.min(*value), max.max(*value)));
+    let (min, max) = if values.is_empty() {(0.0, 1.0)} else if zero {(min.min(0.0), max.max(0.0))} else {(min, max)};
+    if max - min < f32::EPSILON {(min - 1.0, max + 1.0)} else {(min, max)}
+}
+
+/// Returns the height of the line through `points` at `x`.
+fn line_at(points: &[(f32, f32)], x: f32) -> f32 {
+    let index = points.partition_point(|(px, _)| *px < x).clamp(1, points.len() - 1);
+    let ((x0, y0), (x1, y1)) = (points[index - 1], points[index]);
+    let t = if x1 > x0 {((x - x0) / (x1 - x0)).clamp(0.0, 1.0)} else {0.0};
+    y0 + (y1 - y0) * t
+}
+
+#[derive(Debug, Default)]
+struct ChartFrame {
+    size: (f32, f32),
+    /// Where each shape is placed.
+    offsets: Vec<(f32, f32)>,
+    /// The point each label is aligned to, with its horizontal and vertical alignment.
+    labels: Vec<((f32, f32), (Offset, Offset))>,
+    /// Where the shapes of the crosshair are placed.
+    crosshair: Vec<(f32, f32)>,
+}
+
+/// Places the shapes, labels and crosshair of a [`Chart`] where they were last drawn, and remembers its size for the next drawing.
+#[derive(Debug)]
+struct ChartLayout(Arc<Mutex<ChartFrame>>, Size, f32);
+
+impl Layout for ChartLayout {
+    fn request_size(&self, _ctx: &mut Context, _children: Vec<SizeRequest>) -> SizeRequest {
+        let (min, max) = self.1.get(vec![], Size::max);
+        SizeRequest::new(min, self.2, max, self.2)
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let mut frame = self.0.lock().unwrap();
+        frame.size = size;
+        let (shapes, labels) = (frame.offsets.len(), frame.labels.len());
+        children.into_iter().enumerate().map(|(index, child)| {
+            let item = (child.min_width(), child.min_height());
+            let offset = match index {
+                index if index < shapes => frame.offsets[index],
+                index if index < shapes + labels => {
+                    let ((x, y), (align_x, align_y)) = frame.labels[index - shapes];
+                    ((x - align_x.get(item.0, 0.0)).clamp(0.0, (size.0 - item.0).max(0.0)), y - align_y.get(item.1, 0.0))
+                },
+                index => frame.crosshair.get(index - shapes - labels).copied().unwrap_or_default(),
+            };
+            Area{offset, size: item}
+        }).collect()
+    }
+}
@@ -0,0 +1,209 @@
+use pelican_ui::{
+    Area, Component, Context,
+    Drawable, Event, Image, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+};
+
+use crate::elements::{AspectRatioImage, EncodedImage};
+use crate::events::{AttachmentEvent, InputEditedEvent, MessageComposedEvent};
+use crate::components::{ButtonState, IconButton, TextInput};
+use crate::layout::{Column, Offset, Padding, Row, Scroll, ScrollAnchor, Size, Stack};
+
+use std::sync::mpsc::{self, Receiver, Sender};
+
+#[derive(Debug)]
+enum ComposerAction {
+    Send,
+    /// Removes the attachment of the thumbnail with this id.
+    Remove(u64),
+}
+
+/// ## Message Composer
+///
+/// Chat input with a send button, usually placed in a [`Bumper`](crate::Bumper) with [`Bumper::composer`](crate::Bumper::composer).
+///
+/// The input grows with its text up to `max_lines`, then scrolls. Images attached with the photo button
+/// of the [`MobileKeyboard`](crate::MobileKeyboard), or with [`MessageComposer::add_attachment`], are shown
+/// as thumbnails above the input and can be removed.
+///
+/// The send button is enabled once there is text or an attachment. Sending triggers a
+/// [`MessageComposedEvent`] with the text and attachments, and clears the composer.
+///
+/// ### Example
+/// ```rust
+/// let composer = MessageComposer::new(ctx, "Message", 5);
+/// let bumper = Bumper::composer(ctx, composer);
+/// ```
+#[derive(Component)]
+pub struct MessageComposer(
+    Stack,
+    ComposerContent,
+    #[skip] Vec<String>,
+    #[skip] (Sender<ComposerAction>, Receiver<ComposerAction>),
+    #[skip] u64,
+);
+
+impl MessageComposer {
+    pub fn new(ctx: &mut Context, placeholder: &str, max_lines: usize) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let send = sender.clone();
+        let mut button = IconButton::secondary(ctx, "send", move |_ctx: &mut Context| {let _ = send.send(ComposerAction::Send);});
+        *button.status() = ButtonState::Disabled;
+        button.color(ctx, ButtonState::Disabled);
+
+        let input = TextInput::new(ctx, None, None, placeholder, None, TextInput::NO_ICON, true);
+        let line_height = ctx.theme.fonts.size.md * 1.25;
+        let field = ComposerField::new(input, ComposerField::PADDING + line_height * max_lines.max(1) as f32);
+
+        MessageComposer(
+            Stack(Offset::Start, Offset::End, Size::fill(), Size::Fit, Padding::default()),
+            ComposerContent(
+                Column::new(8.0, Offset::Start, Size::fill(), Padding::default()),
+                None,
+                ComposerRow(Row::new(8.0, Offset::End, Size::fill(), Padding::default()), field, button)
+            ),
+            Vec::new(),
+            (sender, receiver),
+            0
+        )
+    }
+
+    /// Returns the text typed so far.
+    pub fn text(&mut self) -> &mut String { self.1.2.1.1.value() }
+
+    /// Returns the attached images, encoded with [`EncodedImage::encode`].
+    pub fn attachments(&self) -> &[String] { &self.2 }
+
+    /// Attaches an image encoded with [`EncodedImage::encode`].
+    pub fn add_attachment(&mut self, ctx: &mut Context, image: String) {
+        let (id, sender) = (self.4, self.3.0.clone());
+        self.4 += 1;
+        let thumbnail = Thumbnail::new(ctx, &image, id, move |_ctx: &mut Context| {let _ = sender.send(ComposerAction::Remove(id));});
+        self.2.push(image);
+        match self.1.1.as_mut() {
+            Some(tray) => tray.1.push(thumbnail),
+            None => self.1.1 = Some(AttachmentTray(Row::new(8.0, Offset::Start, Size::Fit, Padding::default()), vec![thumbnail])),
+        }
+    }
+
+    /// Removes the attachment at `index`.
+    pub fn remove_attachment(&mut self, index: usize) {
+        if index >= self.2.len() { return; }
+        self.2.remove(index);
+        if let Some(tray) = self.1.1.as_mut() { tray.1.remove(index); }
+        if self.2.is_empty() { self.1.1 = None; }
+    }
+
+    /// Returns `true` when there is something to send.
+    pub fn has_content(&mut self) -> bool {
+        !self.text().trim().is_empty() || !self.2.is_empty()
+    }
+
+    /// Sends the message if there is one, the same as pressing the send button.
+    pub fn send(&mut self, ctx: &mut Context) {
+        if !self.has_content() { return; }
+        let text = self.text().trim().to_string();
+        let attachments = std::mem::take(&mut self.2);
+        self.text().clear();
+        self.1.1 = None;
+        ctx.trigger_event(MessageComposedEvent{text, attachments});
+        ctx.trigger_event(InputEditedEvent);
+    }
+}
+
+impl OnEvent for MessageComposer {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            let actions = self.3.1.try_iter().collect::<Vec<_>>();
+            for action in actions {
+                match action {
+                    ComposerAction::Send => self.send(ctx),
+                    ComposerAction::Remove(id) => {
+                        let index = self.1.1.as_ref().and_then(|tray| tray.1.iter().position(|thumbnail| thumbnail.3 == id));
+                        if let Some(index) = index { self.remove_attachment(index); }
+                    },
+                }
+            }
+
+            let enabled = self.has_content();
+            let button = &mut self.1.2.2;
+            if enabled == (*button.status() == ButtonState::Disabled) {
+                let state = if enabled {ButtonState::Default} else {ButtonState::Disabled};
+                *button.status() = state;
+                button.color(ctx, state);
+            }
+        } else if let Some(AttachmentEvent(image)) = event.downcast_ref::<AttachmentEvent>() {
+            self.add_attachment(ctx, image.clone());
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for MessageComposer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MessageComposer({} attachments)", self.2.len())
+    }
+}
+
+#[derive(Debug, Component)]
+struct ComposerContent(Column, Option<AttachmentTray>, ComposerRow);
+impl OnEvent for ComposerContent {}
+
+#[derive(Debug, Component)]
+struct ComposerRow(Row, ComposerField, IconButton);
+impl OnEvent for ComposerRow {}
+
+/// The input of a [`MessageComposer`], scrolling once it is taller than its maximum height.
+#[derive(Debug, Component)]
+struct ComposerField(Scroll, TextInput);
+
+impl ComposerField {
+    /// Height of an input around its text.
+    const PADDING: f32 = 32.0;
+
+    fn new(input: TextInput, max_height: f32) -> Self {
+        let height = Size::custom(move |heights: Vec<(f32, f32)>| {
+            let height = heights[0].0.min(max_height);
+            (height, height)
+        });
+        // Anchored to the end, so the line being typed stays in view.
+        ComposerField(Scroll::new(Offset::Start, Offset::End, Size::fill(), height, Padding::default(), ScrollAnchor::End), input)
+    }
+}
+
+impl OnEvent for ComposerField {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(MouseEvent { state: MouseState::Scroll(_, y), position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.adjust_scroll(*y);
+        } else if event.downcast_ref::<InputEditedEvent>().is_some() {
+            self.0.set_scroll(0.0);
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            self.0.tick();
+        }
+        true
+    }
+}
+
+#[derive(Debug, Component)]
+struct AttachmentTray(Row, Vec<Thumbnail>);
+impl OnEvent for AttachmentTray {}
+
+/// An attached image with a button to remove it, and the id the button removes it by.
+#[derive(Debug, Component)]
+struct Thumbnail(Stack, Image, IconButton, #[skip] u64);
+impl OnEvent for Thumbnail {}
+
+impl Thumbnail {
+    const SIZE: f32 = 72.0;
+
+    fn new(ctx: &mut Context, image: &String, id: u64, on_remove: impl FnMut(&mut Context) + 'static) -> Self {
+        let image = EncodedImage::decode(ctx, image);
+        Thumbnail(
+            Stack(Offset::End, Offset::Start, Size::Static(Self::SIZE), Size::Fit, Padding::default()),
+            AspectRatioImage::new(image, (Self::SIZE, Self::SIZE)),
+            IconButton::secondary(ctx, "close", on_remove),
+            id
+        )
+    }
+}
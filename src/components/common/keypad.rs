@@ -0,0 +1,92 @@
+use pelican_ui::{
+    Area, Component, Context,
+    Drawable, Event, Layout,
+    OnEvent, SizeRequest,
+    Key, NamedKey,
+};
+
+use crate::components::{Button, ButtonState};
+use crate::layout::{Column, Offset, Padding, Row, Size};
+
+use std::sync::mpsc::Sender;
+
+/// A key of the [`Keypad`], or of the keyboard on desktop and web.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum KeypadKey {
+    Digit(char),
+    Decimal,
+    Backspace,
+    /// Switches between entering bitcoin and fiat in an [`AmountInput`](crate::AmountInput).
+    Toggle,
+}
+
+impl KeypadKey {
+    /// Reads a key typed on a keyboard.
+    pub(crate) fn from_key(key: &Key) -> Option<Self> {
+        match key {
+            Key::Named(NamedKey::Backspace | NamedKey::Delete) => Some(KeypadKey::Backspace),
+            Key::Character(c) => match c.chars().next() {
+                Some(digit) if digit.is_ascii_digit() => Some(KeypadKey::Digit(digit)),
+                Some('.' | ',') => Some(KeypadKey::Decimal),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The keys 1 to 9, then the decimal point, 0 and backspace, built from [`Button::keypad`].
+/// Pressed keys are sent to `sender`. Without a decimal point its key is left blank.
+#[derive(Debug, Component)]
+pub(crate) struct Keypad(Column, Vec<KeypadRow>, #[skip] bool);
+impl OnEvent for Keypad {}
+
+impl Keypad {
+    pub(crate) fn new(ctx: &mut Context, sender: Sender<KeypadKey>, decimal: bool) -> Self {
+        let key = |ctx: &mut Context, label: Option<&str>, icon: Option<&'static str>, key: KeypadKey| {
+            let sender = sender.clone();
+            Button::keypad(ctx, label, icon, move |_ctx: &mut Context| {let _ = sender.send(key);})
+        };
+
+        let mut rows = ["123", "456", "789"].iter().map(|digits| {
+            KeypadRow::new(digits.chars().map(|d| key(ctx, Some(&d.to_string()), None, KeypadKey::Digit(d))).collect())
+        }).collect::<Vec<_>>();
+        rows.push(KeypadRow::new(vec![
+            key(ctx, decimal.then_some("."), None, KeypadKey::Decimal),
+            key(ctx, Some("0"), None, KeypadKey::Digit('0')),
+            key(ctx, None, Some("backspace"), KeypadKey::Backspace),
+        ]));
+
+        let mut keypad = Keypad(Column::new(8.0, Offset::Center, Size::fill(), Padding::default()), rows, true);
+        keypad.set_decimal(ctx, decimal);
+        keypad
+    }
+
+    /// Enables the decimal point key for units with decimal places.
+    pub(crate) fn set_decimal(&mut self, ctx: &mut Context, enabled: bool) {
+        self.2 = enabled;
+        let Some(button) = self.1.last_mut().and_then(|row| row.1.first_mut()) else { return; };
+        if (*button.status() != ButtonState::Disabled) == enabled { return; }
+        *button.status() = if enabled {ButtonState::Default} else {ButtonState::Disabled};
+        button.color(ctx);
+    }
+
+    /// Enables or disables every key, e.g. while a [`Passcode`](crate::Passcode) is locked.
+    pub(crate) fn set_disabled(&mut self, ctx: &mut Context, disabled: bool) {
+        self.1.iter_mut().flat_map(|row| row.1.iter_mut()).for_each(|button| {
+            *button.status() = if disabled {ButtonState::Disabled} else {ButtonState::Default};
+            button.color(ctx);
+        });
+        if !disabled { self.set_decimal(ctx, self.2); }
+    }
+}
+
+#[derive(Debug, Component)]
+struct KeypadRow(Row, Vec<Button>);
+impl OnEvent for KeypadRow {}
+
+impl KeypadRow {
+    fn new(keys: Vec<Button>) -> Self {
+        KeypadRow(Row::new(8.0, Offset::Center, Size::fill(), Padding::default()), keys)
+    }
+}
@@ -0,0 +1,315 @@
+use pelican_ui::{Area, Component, Context, Drawable, Event, Layout, MouseEvent, MouseState, OnEvent, SizeRequest, TickEvent};
+
+use crate::events::{AdjustScrollEvent, ScrollChangedEvent, ScrollToEvent};
+use crate::layout::{Offset, ScrollAnchor, ScrollMotion, ScrollPhysics};
+use crate::utils::ElementID;
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+type ItemBuilder = Box<dyn FnMut(&mut Context, usize) -> Box<dyn Drawable>>;
+type ScrollRequest = (Arc<Mutex<Option<(f32, f32)>>>, Offset, bool);
+
+/// ## Lazy List
+///
+/// A vertical scrollable list that only builds the rows inside the viewport plus an overscan window.
+/// Rows are created on demand by the builder closure. Rows that leave the window are kept in a small
+/// cache and handed back if the same rows scroll into view again, otherwise they are dropped.
+///
+/// Row heights are measured once a row has been built. Rows that have not been built yet use the
+/// average of the measured heights, or the given estimate when nothing has been measured.
+///
+/// Use [`ScrollAnchor::End`] for chat-style lists that start at the bottom and grow upwards.
+///
+/// The list scrolls itself with the same [`ScrollPhysics`] as a [`Scroll`](crate::Scroll) layout, so a [`Content`](crate::Content)
+/// holding it stays in place. Like a `Content`, it triggers a [`ScrollChangedEvent`] carrying its [`ElementID`]
+/// whenever it is scrolled, and scrolls to rows requested through a [`ScrollToEvent`] once they are built.
+///
+/// ### Example
+/// ```rust
+/// let list = LazyList::new(ctx, transactions.len(), 72.0, 0.0, ScrollAnchor::Start, move |ctx: &mut Context, index: usize| {
+///     let tx = &transactions[index];
+///     Box::new(ListItem::new(ctx, true, &tx.title, None, Some(&tx.date), None, Some(&tx.amount), None, None, None, None, true, |_| {})) as Box<dyn Drawable>
+/// });
+/// let content = Content::new(ctx, Offset::Start, vec![Box::new(list)]);
+/// ```
+#[derive(Component)]
+pub struct LazyList(
+    LazyLayout,
+    Vec<Box<dyn Drawable>>,
+    #[skip] ItemBuilder,
+    #[skip] HashMap<usize, Box<dyn Drawable>>,
+    #[skip] usize,
+    #[skip] ElementID,
+    #[skip] Option<ScrollRequest>,
+    #[skip] Option<(f32, f32)>,
+);
+
+impl LazyList {
+    pub const OVERSCAN: usize = 4;
+
+    pub fn new(
+        ctx: &mut Context,
+        count: usize,
+        estimate: f32,
+        spacing: f32,
+        anchor: ScrollAnchor,
+        builder: impl FnMut(&mut Context, usize) -> Box<dyn Drawable> + 'static,
+    ) -> Self {
+        let state = LazyState {
+            heights: vec![None; count],
+            estimate,
+            spacing,
+            start: 0,
+            scroll: 0.0,
+            motion: ScrollMotion::default(),
+            viewport: (0.0, 0.0),
+            anchor,
+        };
+        let layout = LazyLayout(Arc::new(Mutex::new(state)));
+        let mut list = LazyList(layout, Vec::new(), Box::new(builder), HashMap::new(), Self::OVERSCAN, ElementID::new(), None, None);
+        list.update(ctx);
+        list
+    }
+
+    /// Sets how many rows outside the viewport are built on each side.
+    pub fn set_overscan(&mut self, overscan: usize) {
+        self.4 = overscan;
+    }
+
+    /// Returns the total number of rows.
+    pub fn count(&self) -> usize {
+        self.0.0.lock().unwrap().heights.len()
+    }
+
+    /// Changes the total number of rows. Rows that are already built are kept.
+    pub fn set_count(&mut self, ctx: &mut Context, count: usize) {
+        self.0.0.lock().unwrap().heights.resize(count, None);
+        self.3.retain(|i, _| *i < count);
+        self.update(ctx);
+    }
+
+    /// Drops every built row so they are rebuilt from the builder.
+    pub fn refresh(&mut self, ctx: &mut Context) {
+        self.1.clear();
+        self.3.clear();
+        self.0.0.lock().unwrap().heights.iter_mut().for_each(|h| *h = None);
+        self.update(ctx);
+    }
+
+    /// Returns the range of row indices that are currently built.
+    pub fn range(&self) -> Range<usize> {
+        let start = self.0.0.lock().unwrap().start;
+        start..start+self.1.len()
+    }
+
+    /// Returns the rows that are currently built, starting at the first index of [`range`](Self::range).
+    pub fn items(&mut self) -> &mut Vec<Box<dyn Drawable>> {&mut self.1}
+
+    /// Returns the id reported in this list's [`ScrollChangedEvent`]s.
+    pub fn id(&self) -> ElementID {self.5}
+
+    /// Sets how the list moves after the user lets go, the platform default otherwise.
+    pub fn set_physics(&mut self, physics: ScrollPhysics) {
+        self.0.0.lock().unwrap().motion.set_physics(physics);
+    }
+
+    pub fn adjust_scroll(&mut self, delta: f32) {
+        let state = &mut *self.0.0.lock().unwrap();
+        let delta = match state.anchor {
+            ScrollAnchor::Start => delta,
+            ScrollAnchor::End => -delta,
+        };
+        state.scroll = state.motion.input(state.scroll, delta);
+    }
+
+    pub fn set_scroll(&mut self, val: f32) {
+        let state = &mut *self.0.0.lock().unwrap();
+        state.scroll = state.motion.move_to(state.scroll, val, false);
+    }
+
+    /// Returns the distance from the top of the list to the top of the viewport.
+    pub fn scroll_offset(&self) -> f32 {
+        let state = self.0.0.lock().unwrap();
+        state.top(state.motion.max()).clamp(0.0, state.motion.max())
+    }
+
+    /// Returns the largest possible [`scroll_offset`](Self::scroll_offset) from the last layout.
+    pub fn max_offset(&self) -> f32 {
+        self.0.0.lock().unwrap().motion.max()
+    }
+
+    fn update(&mut self, ctx: &mut Context) {
+        let (range, start) = {
+            let state = self.0.0.lock().unwrap();
+            (state.visible(self.4), state.start)
+        };
+        if range.start == start && range.len() == self.1.len() { return; }
+
+        let mut built = std::mem::take(&mut self.1).into_iter().enumerate().map(|(k, item)| (start+k, item)).collect::<HashMap<_, _>>();
+        self.1 = range.clone().map(|i| {
+            built.remove(&i).or_else(|| self.3.remove(&i)).unwrap_or_else(|| (self.2)(ctx, i))
+        }).collect();
+
+        // Keep the rows closest to the window around in case they scroll back into view.
+        self.3.extend(built);
+        let capacity = self.4*2;
+        if self.3.len() > capacity {
+            let distance = |i: usize| if i < range.start {range.start - i} else {(i + 1).saturating_sub(range.end)};
+            let mut indices = self.3.keys().copied().collect::<Vec<_>>();
+            indices.sort_by_key(|i| distance(*i));
+            indices.into_iter().skip(capacity).for_each(|i| {self.3.remove(&i);});
+        }
+
+        self.0.0.lock().unwrap().start = range.start;
+    }
+}
+
+impl OnEvent for LazyList {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            {
+                let state = &mut *self.0.0.lock().unwrap();
+                if let Some((target, align, animated)) = self.6.take() {
+                    if let Some((position, size)) = *target.lock().unwrap() {
+                        let target = state.motion.scroll_target(state.scroll, position, size, align, state.anchor);
+                        state.scroll = state.motion.move_to(state.scroll, target, animated);
+                    }
+                }
+                state.scroll = state.motion.tick(state.scroll);
+            }
+            self.update(ctx);
+
+            let (offset, max_offset) = (self.scroll_offset(), self.max_offset());
+            let changed = self.7.map(|(o, m)| (o - offset).abs() > 0.5 || (m - max_offset).abs() > 0.5).unwrap_or(true);
+            if changed {
+                self.7 = Some((offset, max_offset));
+                ctx.trigger_event(ScrollChangedEvent{
+                    id: self.5, offset, max_offset,
+                    at_top: offset <= 0.5,
+                    at_bottom: offset >= max_offset - 0.5,
+                });
+            }
+        } else if let Some(AdjustScrollEvent::Vertical(a)) = event.downcast_ref::<AdjustScrollEvent>() {
+            self.adjust_scroll(*a);
+        } else if let Some(scroll_to) = event.downcast_mut::<ScrollToEvent>() {
+            self.6 = Some((scroll_to.capture(), scroll_to.align, scroll_to.animated));
+        } else if let Some(MouseEvent { state: MouseState::Scroll(_, y), position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.adjust_scroll(*y);
+        } else if let Some(MouseEvent { state: MouseState::Pressed, position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.0.lock().unwrap().motion.hold();
+        } else if let Some(MouseEvent { state: MouseState::Released, .. }) = event.downcast_ref::<MouseEvent>() {
+            self.0.0.lock().unwrap().motion.release();
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for LazyList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LazyList")
+    }
+}
+
+#[derive(Debug)]
+struct LazyState {
+    heights: Vec<Option<f32>>,
+    estimate: f32,
+    spacing: f32,
+    start: usize,
+    scroll: f32,
+    motion: ScrollMotion,
+    viewport: (f32, f32),
+    anchor: ScrollAnchor,
+}
+
+impl LazyState {
+    fn estimate(&self) -> f32 {
+        let (total, count) = self.heights.iter().flatten().fold((0.0, 0), |(t, c), h| (t + h, c + 1));
+        if count > 0 { total / count as f32 } else { self.estimate }
+    }
+
+    fn height(&self, index: usize, estimate: f32) -> f32 {
+        self.heights[index].unwrap_or(estimate)
+    }
+
+    fn total(&self, estimate: f32) -> f32 {
+        let count = self.heights.len();
+        (0..count).map(|i| self.height(i, estimate)).sum::<f32>() + self.spacing*count.saturating_sub(1) as f32
+    }
+
+    /// Distance between the top of the list and the top of the viewport, including any overscroll.
+    fn top(&self, max_scroll: f32) -> f32 {
+        match self.anchor {
+            ScrollAnchor::Start => self.scroll,
+            ScrollAnchor::End => max_scroll - self.scroll,
+        }
+    }
+
+    fn visible(&self, overscan: usize) -> Range<usize> {
+        let count = self.heights.len();
+        if count == 0 { return 0..0; }
+
+        let estimate = self.estimate();
+        let max_scroll = (self.total(estimate) - self.viewport.1).max(0.0);
+        let top = self.top(max_scroll);
+        let bottom = top + self.viewport.1;
+
+        let (mut first, mut last) = (None, count);
+        let mut y = 0.0;
+        for i in 0..count {
+            if y > bottom {
+                last = i;
+                break;
+            }
+            let h = self.height(i, estimate);
+            if first.is_none() && y + h >= top { first = Some(i); }
+            y += h + self.spacing;
+        }
+
+        let first = first.unwrap_or(count-1);
+        first.saturating_sub(overscan)..(last.max(first+1) + overscan).min(count)
+    }
+}
+
+#[derive(Debug)]
+struct LazyLayout(Arc<Mutex<LazyState>>);
+
+impl Layout for LazyLayout {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let width = children.iter().fold(0.0f32, |w, c| w.max(c.min_width()));
+        SizeRequest::new(width, 0.0, f32::MAX, f32::MAX)
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let state = &mut *self.0.lock().unwrap();
+        state.viewport = size;
+
+        let start = state.start;
+        children.iter().enumerate().for_each(|(k, c)| {
+            if let Some(h) = state.heights.get_mut(start+k) { *h = Some(c.min_height()); }
+        });
+
+        let estimate = state.estimate();
+        let total = state.total(estimate);
+        let max_scroll = (total - size.1).max(0.0);
+        // Row heights including the spacing after them, for snapping to rows.
+        let heights = (0..state.heights.len()).map(|i| state.height(i, estimate) + state.spacing).collect::<Vec<_>>();
+        state.scroll = state.motion.layout(state.scroll, max_scroll, size.1, &heights, state.anchor);
+
+        let top = state.top(max_scroll);
+        let base = match state.anchor {
+            ScrollAnchor::End if total < size.1 => size.1 - total,
+            _ => 0.0
+        };
+        let mut y = (0..start).map(|i| state.height(i, estimate) + state.spacing).sum::<f32>() - top + base;
+
+        children.into_iter().map(|c| {
+            let size = c.get((size.0, c.min_height()));
+            let area = Area{offset: (0.0, y), size};
+            y += size.1 + state.spacing;
+            area
+        }).collect()
+    }
+}
@@ -5,17 +5,20 @@ use pelican_ui::{
     SizeRequest,
 };
 
-use crate::events::ListItemSelect;
+use crate::events::{ListItemSelect, ScrollToEvent};
 use crate::elements::{Rectangle, Icon, Text, ExpandableText, TextStyle};
 use crate::components::{ButtonState, Avatar, AvatarContent};
 use crate::layout::{Column, Stack, Row, Padding, Offset, Size, Opt};
 use crate::utils::ElementID;
 
+use std::sync::{Arc, Mutex};
+
 /// ## List Item
 ///
 /// A versatile row-style component used for lists, menus, or settings screens.  
 /// Supports a title, optional flair (badge), subtitle, description, right-aligned  
 /// content, radio buttons, and avatar/circle icons.  
+/// A [`Toggle`](crate::Toggle), [`Checkbox`](crate::Checkbox) or other control can be placed at the end with [`ListItem::trailing`].
 ///
 /// <img src="https://raw.githubusercontent.com/ramp-stack/pelican_ui_std/main/src/examples/list_item.png"
 ///      alt="List Item Example"
@@ -78,6 +81,15 @@ impl ListItem {
     pub fn title(&mut self) -> &mut TitleRow {self.2.data().left().title()}
     pub fn subtitle(&mut self) -> &mut Option<ExpandableText> {self.2.data().left().subtitle()}
     pub fn avatar(&mut self) -> &mut Option<Avatar> {&mut self.2.2}
+    /// Returns the trailing control if it exists.
+    pub fn trailing_control(&mut self) -> Option<&mut Box<dyn Drawable>> {self.2.4.as_mut().map(|t| &mut t.1)}
+
+    /// Places a control such as a [`Toggle`](crate::Toggle) or [`Checkbox`](crate::Checkbox) at the end of the list item.
+    /// Presses on the control are handled by the control itself and don't click the list item.
+    pub fn trailing(mut self, control: impl Drawable + 'static) -> Self {
+        self.2.4 = Some(TrailingControl::new(Box::new(control)));
+        self
+    }
 
     pub fn is_selected(&self) -> bool {
         self.2.1.as_ref().map(|r| r.2).unwrap_or(false)
@@ -89,8 +101,14 @@ impl OnEvent for ListItem {
         if let Some(event) = event.downcast_ref::<MouseEvent>() {
             if let MouseEvent{state: MouseState::Pressed, position: Some(_)} = event {
                 self.6 = true;
+                // Set again by the trailing control if the press lands on it.
+                if let Some(trailing) = self.2.4.as_ref() { *trailing.2.lock().unwrap() = false; }
             } else if let MouseEvent{state: MouseState::Released, position: Some(_)} = event {
-                if self.6 {
+                // A press on the trailing control belongs to the control.
+                let on_control = self.2.4.as_ref().is_some_and(|t| std::mem::take(&mut *t.2.lock().unwrap()));
+                if self.6 && on_control {
+                    self.6 = false;
+                } else if self.6 {
                     if let Some(radio) = self.2.1.as_mut() {
                         radio.select(ctx);
                         ctx.trigger_event(ListItemSelect(self.5.expect("Selectable List Items Require ElementIDs")));
@@ -113,8 +131,10 @@ impl OnEvent for ListItem {
                     }
                 }
             }
+        } else if let Some(scroll_to) = event.downcast_ref::<ScrollToEvent>() {
+            if let Some(id) = self.5 { scroll_to.report(id); }
         }
-        false
+        self.2.4.is_some()
     }
 }
 
@@ -125,9 +145,28 @@ impl std::fmt::Debug for ListItem {
 }
 
 #[derive(Debug, Component)]
-struct ListItemContent(Row, Option<RadioButton>, Option<Avatar>, ListItemData, Option<Image>);
+struct ListItemContent(Row, Option<RadioButton>, Option<Avatar>, ListItemData, Option<TrailingControl>, Option<Image>);
 impl OnEvent for ListItemContent {}
 
+/// The control at the end of a [`ListItem`], noting presses that land on it.
+#[derive(Debug, Component)]
+struct TrailingControl(Stack, Box<dyn Drawable>, #[skip] Arc<Mutex<bool>>);
+
+impl TrailingControl {
+    fn new(control: Box<dyn Drawable>) -> Self {
+        TrailingControl(Stack::default(), control, Arc::new(Mutex::new(false)))
+    }
+}
+
+impl OnEvent for TrailingControl {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(MouseEvent{state: MouseState::Pressed, position: Some(_)}) = event.downcast_ref::<MouseEvent>() {
+            *self.2.lock().unwrap() = true;
+        }
+        true
+    }
+}
+
 impl ListItemContent {
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -149,6 +188,7 @@ impl ListItemContent {
             radio_button.map(|enabled| RadioButton::new(ctx, enabled)), 
             circle_icon.map(|data| Avatar::new(ctx, data, None, false, 48.0, None)),
             ListItemData::new(ctx, title, flair, subtitle, description, right_title, right_subtitle, max_lines),
+            None,
             caret.then(|| Icon::new(ctx, "forward", color, 16.0)),
         )
     }
@@ -158,7 +198,11 @@ impl ListItemContent {
 
 #[derive(Debug, Component)]
 struct RadioButton(Row, Image, #[skip] bool); // is selected
-impl OnEvent for RadioButton {}
+
+// Events only reach a list item's children for its trailing control.
+impl OnEvent for RadioButton {
+    fn on_event(&mut self, _ctx: &mut Context, _event: &mut dyn Event) -> bool { false }
+}
 
 impl RadioButton {
     fn new(ctx: &mut Context, is_enabled: bool) -> Self {
@@ -182,7 +226,10 @@ impl RadioButton {
 
 #[derive(Debug, Component)]
 struct ListItemData(Row, LeftData, Option<RightData>);
-impl OnEvent for ListItemData {}
+
+impl OnEvent for ListItemData {
+    fn on_event(&mut self, _ctx: &mut Context, _event: &mut dyn Event) -> bool { false }
+}
 
 impl ListItemData {
     #[allow(clippy::too_many_arguments)]
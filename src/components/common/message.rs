@@ -0,0 +1,440 @@
+use pelican_ui::{
+    Align, Area, Component, Context,
+    Drawable, Event, Image, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+};
+
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+
+use crate::elements::{AspectRatioImage, EncodedImage, Icon, OutlinedRectangle, Text, TextStyle};
+use crate::layout::{Column, Offset, Padding, Row, Scroll, ScrollAnchor, Size, Stack};
+use crate::utils::Timestamp;
+
+/// Which side of a conversation a [`Message`] is from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageDirection {
+    /// Received from someone else, shown on the left.
+    Incoming,
+    /// Sent by the user, shown on the right.
+    Outgoing,
+}
+
+/// The delivery status of an outgoing [`Message`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageStatus {
+    /// Not sent yet.
+    Sending,
+    /// Sent, shown with a checkmark.
+    #[default]
+    Sent,
+    /// Received by the other side, shown with two checkmarks.
+    Delivered,
+    /// Read by the other side, shown with two checkmarks in the brand color.
+    Read,
+    /// Couldn't be sent, shown with an error icon.
+    Failed,
+}
+
+/// A chat message shown by a [`MessageBubble`] and a [`MessageList`].
+///
+/// Images are attachments encoded with [`EncodedImage::encode`], e.g. from an [`AttachmentEvent`](crate::AttachmentEvent).
+///
+/// ```rust
+/// let message = Message::outgoing("Look at this bird!", Timestamp::new(Local::now()))
+///     .images(vec![encoded])
+///     .status(MessageStatus::Sending);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub text: String,
+    pub images: Vec<String>,
+    pub direction: MessageDirection,
+    pub status: MessageStatus,
+    pub timestamp: Timestamp,
+    /// The name shown above the first message of a group, e.g. in group chats.
+    pub author: Option<String>,
+}
+
+impl Message {
+    pub fn incoming(text: &str, timestamp: Timestamp) -> Self {
+        Message{text: text.to_string(), images: Vec::new(), direction: MessageDirection::Incoming, status: MessageStatus::Sent, timestamp, author: None}
+    }
+
+    pub fn outgoing(text: &str, timestamp: Timestamp) -> Self {
+        Message{text: text.to_string(), images: Vec::new(), direction: MessageDirection::Outgoing, status: MessageStatus::Sent, timestamp, author: None}
+    }
+
+    pub fn images(mut self, images: Vec<String>) -> Self {
+        self.images = images;
+        self
+    }
+
+    pub fn status(mut self, status: MessageStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn author(mut self, author: &str) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    /// Returns the time of the message, or `None` while it is pending.
+    fn datetime(&self) -> Option<DateTime<Local>> {
+        (self.timestamp != Timestamp::pending()).then(|| self.timestamp.to_datetime()).flatten()
+    }
+
+    /// Returns `true` when `next` directly follows this message in the same group.
+    fn groups_with(&self, next: &Message) -> bool {
+        let close = match (self.datetime(), next.datetime()) {
+            (Some(a), Some(b)) => a.date_naive() == b.date_naive() && (b - a).num_minutes().abs() <= MessageList::GROUP_MINUTES,
+            _ => true,
+        };
+        self.direction == next.direction && self.author == next.author && close
+    }
+}
+
+/// ## Message Bubble
+///
+/// A chat message, aligned left when incoming and right when outgoing.
+///
+/// Consecutive messages from the same sender are grouped: only the first one shows the author
+/// and only the last one shows the [`Timestamp::friendly`] time and, for outgoing messages, the delivery status.
+/// The [`MessageList`] takes care of the grouping.
+///
+/// ### Example
+/// ```rust
+/// let message = Message::incoming("Hello!", Timestamp::new(Local::now())).author("Ella");
+/// let bubble = MessageBubble::new(ctx, &message, true, true);
+/// ```
+#[derive(Debug, Component)]
+pub struct MessageBubble(Stack, MessageContent);
+impl OnEvent for MessageBubble {}
+
+impl MessageBubble {
+    /// Room kept free on the other side of a bubble.
+    pub const INSET: f32 = 64.0;
+    pub const IMAGE_WIDTH: f32 = 200.0;
+
+    /// Creates a bubble for `message`, `first` and `last` in its group.
+    pub fn new(ctx: &mut Context, message: &Message, first: bool, last: bool) -> Self {
+        let mut bubble = MessageBubble(
+            Stack::default(),
+            MessageContent(Column::new(4.0, Offset::Start, Size::Fit, Padding::default()), None, BubbleBody::new(ctx, message), None)
+        );
+        bubble.set_group(ctx, message, first, last);
+        bubble
+    }
+
+    /// Updates the author, time and delivery status shown for `message`, `first` and `last` in its group.
+    /// The text and images are kept.
+    pub fn set_group(&mut self, ctx: &mut Context, message: &Message, first: bool, last: bool) {
+        let outgoing = message.direction == MessageDirection::Outgoing;
+        let top = if first {12.0} else {2.0};
+        let (align, padding) = match outgoing {
+            true => (Offset::End, Padding(Self::INSET, top, 0.0, 0.0)),
+            false => (Offset::Start, Padding(0.0, top, Self::INSET, 0.0)),
+        };
+        let font_size = ctx.theme.fonts.size;
+
+        self.0 = Stack(align, Offset::Start, Size::fill(), Size::Fit, padding);
+        self.1.0 = Column::new(4.0, align, Size::Fit, Padding::default());
+        self.1.1 = message.author.as_ref().filter(|_| first && !outgoing)
+            .map(|author| Text::new(ctx, author, TextStyle::Secondary, font_size.xs, Align::Left));
+        self.1.3 = last.then(|| MessageMeta::new(ctx, message));
+    }
+
+    /// Updates the time and delivery status shown under the bubble from `message`.
+    pub fn set_status(&mut self, ctx: &mut Context, message: &Message) {
+        if let Some(meta) = self.1.3.as_mut() { *meta = MessageMeta::new(ctx, message); }
+    }
+}
+
+#[derive(Debug, Component)]
+struct MessageContent(Column, Option<Text>, BubbleBody, Option<MessageMeta>);
+impl OnEvent for MessageContent {}
+
+#[derive(Debug, Component)]
+struct BubbleBody(Stack, OutlinedRectangle, BubbleItems);
+impl OnEvent for BubbleBody {}
+
+impl BubbleBody {
+    fn new(ctx: &mut Context, message: &Message) -> Self {
+        let colors = &ctx.theme.colors;
+        let (background, style) = match message.direction {
+            MessageDirection::Outgoing => (colors.brand.primary, TextStyle::White),
+            MessageDirection::Incoming => (colors.background.secondary, TextStyle::Primary),
+        };
+        let font_size = ctx.theme.fonts.size.md;
+
+        let images = message.images.iter().map(|image| {
+            let image = EncodedImage::decode(ctx, image);
+            AspectRatioImage::new(image, (MessageBubble::IMAGE_WIDTH, MessageBubble::IMAGE_WIDTH))
+        }).collect();
+        let text = (!message.text.is_empty()).then(|| BubbleText(Text::new(ctx, &message.text, style, font_size, Align::Left), None));
+
+        BubbleBody(
+            Stack(Offset::Start, Offset::Start, Size::Fit, Size::Fit, Padding(12.0, 8.0, 12.0, 8.0)),
+            OutlinedRectangle::new(background, background, 16.0, 1.0),
+            BubbleItems(Column::new(8.0, Offset::Start, Size::Fit, Padding::default()), images, text)
+        )
+    }
+}
+
+#[derive(Debug, Component)]
+struct BubbleItems(Column, Vec<Image>, Option<BubbleText>);
+impl OnEvent for BubbleItems {}
+
+/// Text that hugs short messages and wraps long ones at the width it's given.
+#[derive(Debug)]
+struct BubbleText(Text, Option<f32>);
+impl OnEvent for BubbleText {}
+
+impl Component for BubbleText {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> { vec![&mut self.0] }
+    fn children(&self) -> Vec<&dyn Drawable> { vec![&self.0] }
+
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let max = self.1.map(|_| f32::MAX).unwrap_or(children[0].min_width());
+        SizeRequest::new(0.0, children[0].min_height(), max, children[0].max_height())
+    }
+
+    fn build(&mut self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        // Wrap once the text doesn't fit, unwrap to measure it again once there is more room.
+        self.1 = match self.1 {
+            None if children[0].min_width() > size.0 => Some(size.0),
+            Some(width) if (size.0 - width).abs() > 0.5 => None,
+            width => width,
+        };
+        self.0.text().width = self.1;
+        vec![Area{offset: (0.0, 0.0), size}]
+    }
+}
+
+/// The time of the last message in a group and, for outgoing messages, its delivery status.
+#[derive(Debug, Component)]
+struct MessageMeta(Row, Text, Vec<Image>);
+impl OnEvent for MessageMeta {}
+
+impl MessageMeta {
+    fn new(ctx: &mut Context, message: &Message) -> Self {
+        let font_size = ctx.theme.fonts.size.xs;
+        let time = match message.datetime().and_then(|_| message.timestamp.friendly()) {
+            _ if message.status == MessageStatus::Failed => "Not delivered".to_string(),
+            Some(time) => time,
+            None => "Sending...".to_string(),
+        };
+        let style = if message.status == MessageStatus::Failed {TextStyle::Error} else {TextStyle::Secondary};
+
+        let colors = &ctx.theme.colors;
+        let icons = match message.direction {
+            MessageDirection::Incoming => vec![],
+            MessageDirection::Outgoing => match message.status {
+                MessageStatus::Sending => vec![],
+                MessageStatus::Sent => vec![("checkmark", colors.text.secondary)],
+                MessageStatus::Delivered => vec![("checkmark", colors.text.secondary); 2],
+                MessageStatus::Read => vec![("checkmark", colors.brand.primary); 2],
+                MessageStatus::Failed => vec![("error", colors.status.danger)],
+            },
+        };
+
+        MessageMeta(
+            Row::new(2.0, Offset::Center, Size::Fit, Padding::default()),
+            Text::new(ctx, &time, style, font_size, Align::Left),
+            icons.into_iter().map(|(icon, color)| Icon::new(ctx, icon, color, 14.0)).collect()
+        )
+    }
+}
+
+/// The day above the first message of each day in a [`MessageList`].
+#[derive(Debug, Component)]
+struct DateSeparator(Stack, Text);
+impl OnEvent for DateSeparator {}
+
+impl DateSeparator {
+    fn new(ctx: &mut Context, date: NaiveDate) -> Self {
+        let today = Local::now().date_naive();
+        let label = match date {
+            _ if date == today => "Today".to_string(),
+            _ if Some(date) == today.pred_opt() => "Yesterday".to_string(),
+            _ if date.year() == today.year() => date.format("%A, %B %-d").to_string(),
+            _ => date.format("%B %-d, %Y").to_string(),
+        };
+        let font_size = ctx.theme.fonts.size.xs;
+        DateSeparator(
+            Stack(Offset::Center, Offset::Center, Size::fill(), Size::Fit, Padding(0.0, 16.0, 0.0, 4.0)),
+            Text::new(ctx, &label, TextStyle::Secondary, font_size, Align::Center)
+        )
+    }
+}
+
+/// A [`MessageBubble`] in a [`MessageList`], below the day when it is the first message of the day.
+#[derive(Debug, Component)]
+struct MessageRow(Column, Option<DateSeparator>, MessageBubble);
+impl OnEvent for MessageRow {}
+
+impl MessageRow {
+    fn new(ctx: &mut Context, messages: &[Message], index: usize) -> Self {
+        let (date, first, last) = Self::group(messages, index);
+        MessageRow(
+            Column::new(0.0, Offset::Start, Size::Fit, Padding::default()),
+            date.map(|date| DateSeparator::new(ctx, date)),
+            MessageBubble::new(ctx, &messages[index], first, last)
+        )
+    }
+
+    /// Updates the day and grouping after the messages around it have changed.
+    fn regroup(&mut self, ctx: &mut Context, messages: &[Message], index: usize) {
+        let (date, first, last) = Self::group(messages, index);
+        self.1 = date.map(|date| DateSeparator::new(ctx, date));
+        self.2.set_group(ctx, &messages[index], first, last);
+    }
+
+    /// Returns the day to show above the message at `index`, if any, and whether it is first and last in its group.
+    fn group(messages: &[Message], index: usize) -> (Option<NaiveDate>, bool, bool) {
+        let message = &messages[index];
+        let date = message.datetime().map(|dt| dt.date_naive());
+        // Pending messages don't have a day, so the day is that of the last message with one.
+        let day = messages[..index].iter().rev().find_map(|message| message.datetime()).map(|dt| dt.date_naive());
+        let separated = date.is_some() && date != day;
+        let first = separated || index == 0 || !messages[index - 1].groups_with(message);
+        let last = !messages.get(index + 1).is_some_and(|next| message.groups_with(next));
+        (date.filter(|_| separated), first, last)
+    }
+}
+
+/// ## Message List
+///
+/// A conversation of [`MessageBubble`]s, starting at the bottom with the newest message.
+///
+/// The list stays at the bottom as messages are pushed, unless it has been scrolled up to read older ones.
+/// Messages are grouped by sender and separated by day. When it is scrolled to the top, `on_load_older`
+/// is called to fetch older messages, which are added with [`MessageList::prepend`].
+///
+/// ### Example
+/// ```rust
+/// let list = MessageList::new(ctx, messages, |ctx: &mut Context| ctx.trigger_event(LoadOlderMessages));
+/// let content = Content::new(ctx, Offset::End, vec![Box::new(list)]);
+/// ```
+#[derive(Component)]
+pub struct MessageList(
+    Scroll,
+    Vec<MessageRow>,
+    #[skip] Vec<Message>,
+    #[skip] Box<dyn FnMut(&mut Context)>,
+    #[skip] MessageListState,
+);
+
+impl MessageList {
+    /// Messages this many minutes apart or less are grouped.
+    pub const GROUP_MINUTES: i64 = 5;
+    /// Distance from the top at which older messages are loaded.
+    pub const LOAD_THRESHOLD: f32 = 48.0;
+
+    pub fn new(ctx: &mut Context, messages: Vec<Message>, on_load_older: impl FnMut(&mut Context) + 'static) -> Self {
+        let state = MessageListState{loading: false, has_older: true, scrolled: false, keep: None};
+        let scroll = Scroll::new(Offset::Start, Offset::End, Size::fill(), Size::fill(), Padding::new(16.0), ScrollAnchor::End);
+        let rows = (0..messages.len()).map(|index| MessageRow::new(ctx, &messages, index)).collect();
+        MessageList(scroll, rows, messages, Box::new(on_load_older), state)
+    }
+
+    /// Returns the messages, oldest first.
+    pub fn messages(&self) -> &[Message] { &self.2 }
+
+    /// Adds a new message at the bottom.
+    pub fn push(&mut self, ctx: &mut Context, message: Message) {
+        if !self.is_at_bottom() && self.4.keep.is_none() {
+            self.4.keep = Some((self.0.scroll_offset(), self.0.max_offset()));
+        }
+        self.2.push(message);
+        let index = self.2.len() - 1;
+        self.1.push(MessageRow::new(ctx, &self.2, index));
+        if index > 0 { self.regroup(ctx, index - 1); }
+    }
+
+    /// Adds older messages at the top, oldest first, keeping the visible messages in place.
+    /// Pass `has_older: false` once there is nothing older left to load.
+    pub fn prepend(&mut self, ctx: &mut Context, messages: Vec<Message>, has_older: bool) {
+        let count = messages.len();
+        self.2.splice(0..0, messages);
+        let rows = (0..count).map(|index| MessageRow::new(ctx, &self.2, index)).collect::<Vec<_>>();
+        self.1.splice(0..0, rows);
+        // Pending messages don't have a day, so the rows up to the first one with a day may have a new separator.
+        let end = self.2[count..].iter().position(|message| message.datetime().is_some()).map_or(self.2.len(), |index| count + index + 1);
+        (count..end).for_each(|index| self.regroup(ctx, index));
+        self.4.loading = false;
+        self.4.has_older = has_older;
+    }
+
+    /// Updates the delivery status of the message at `index`.
+    pub fn set_status(&mut self, ctx: &mut Context, index: usize, status: MessageStatus) {
+        let Some(message) = self.2.get_mut(index) else { return; };
+        message.status = status;
+        self.1[index].2.set_status(ctx, &self.2[index]);
+    }
+
+    /// Returns `true` when the newest message is in view.
+    pub fn is_at_bottom(&self) -> bool {
+        self.0.scroll_offset() >= self.0.max_offset() - 1.0
+    }
+
+    /// Scrolls down to the newest message.
+    pub fn scroll_to_bottom(&mut self) {
+        self.4.keep = None;
+        self.0.set_scroll(0.0);
+    }
+
+    /// Updates the row at `index` after messages next to it were added.
+    fn regroup(&mut self, ctx: &mut Context, index: usize) {
+        if index < self.1.len() { self.1[index].regroup(ctx, &self.2, index); }
+    }
+}
+
+impl OnEvent for MessageList {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(MouseEvent { state: MouseState::Scroll(_, y), position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.adjust_scroll(*y);
+            self.4.scrolled = true;
+            self.4.keep = None;
+        } else if let Some(MouseEvent { state: MouseState::Pressed, position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.hold();
+        } else if let Some(MouseEvent { state: MouseState::Released, .. }) = event.downcast_ref::<MouseEvent>() {
+            self.0.release();
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            self.0.tick();
+
+            // Keep the messages in view when new ones are pushed below them.
+            if let Some((offset, max)) = self.4.keep {
+                let new_max = self.0.max_offset();
+                if new_max != max {
+                    self.0.set_scroll(new_max - offset);
+                    self.4.keep = None;
+                }
+            }
+
+            let state = &mut self.4;
+            if state.scrolled && state.has_older && !state.loading && self.0.scroll_offset() <= Self::LOAD_THRESHOLD {
+                state.loading = true;
+                (self.3)(ctx);
+            }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for MessageList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MessageList({} messages)", self.2.len())
+    }
+}
+
+#[derive(Debug)]
+struct MessageListState {
+    loading: bool,
+    has_older: bool,
+    /// Set once the user has scrolled, so older messages aren't loaded before the list is laid out.
+    scrolled: bool,
+    /// The offset from the top and the maximum offset before pushing while scrolled up.
+    keep: Option<(f32, f32)>,
+}
@@ -0,0 +1,315 @@
+use pelican_ui::{
+    Align, Area, Component, Context,
+    Drawable, Event, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+};
+
+use crate::elements::{OutlinedRectangle, Text, TextStyle};
+use crate::events::{InputEditedEvent, TextInputSelect};
+use crate::components::{Button, TextInput};
+use crate::layout::{Column, Grid, Offset, Padding, Row, Size, Stack, Wrap};
+
+use super::text_input::InputState;
+
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// ## Mnemonic Display
+///
+/// Numbered grid of the words of a seed phrase, e.g. 12 or 24 BIP39 words.
+///
+/// The words are masked until they're pressed, and masked again once released.
+/// Use [`MnemonicDisplay::set_revealed`] to keep them visible.
+///
+/// ### Example
+/// ```rust
+/// let words = mnemonic.words().collect::<Vec<_>>();
+/// let display = MnemonicDisplay::new(ctx, &words);
+/// ```
+#[derive(Debug, Component)]
+pub struct MnemonicDisplay(Column, MnemonicGrid, Option<Text>, #[skip] bool, #[skip] bool);
+
+impl MnemonicDisplay {
+    pub fn new(ctx: &mut Context, words: &[impl AsRef<str>]) -> Self {
+        let font_size = ctx.theme.fonts.size.sm;
+        let words = words.iter().enumerate().map(|(i, word)| MnemonicWord::new(ctx, i + 1, word.as_ref())).collect();
+
+        MnemonicDisplay(
+            Column::new(16.0, Offset::Center, Size::fill(), Padding::default()),
+            MnemonicGrid(Wrap::new(8.0, 8.0), words),
+            Some(Text::new(ctx, "Press and hold to reveal", TextStyle::Secondary, font_size, Align::Center)),
+            false,
+            false
+        )
+    }
+
+    /// Keeps the words visible, or masks them again.
+    pub fn set_revealed(&mut self, ctx: &mut Context, revealed: bool) {
+        self.3 = revealed;
+        self.2 = match revealed {
+            true => None,
+            false => {
+                let font_size = ctx.theme.fonts.size.sm;
+                Some(Text::new(ctx, "Press and hold to reveal", TextStyle::Secondary, font_size, Align::Center))
+            }
+        };
+        self.1.reveal(revealed || self.4);
+    }
+
+    /// Returns `true` while the words are visible.
+    pub fn is_revealed(&self) -> bool { self.3 || self.4 }
+}
+
+impl OnEvent for MnemonicDisplay {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            let pressed = match event {
+                MouseEvent{state: MouseState::Pressed, position: Some(_)} => true,
+                MouseEvent{state: MouseState::Released, ..} => false,
+                _ => return true,
+            };
+            if pressed && !self.4 { ctx.hardware.haptic(); }
+            self.4 = pressed;
+            self.1.reveal(self.3 || self.4);
+        }
+        true
+    }
+}
+
+#[derive(Debug, Component)]
+struct MnemonicGrid(Wrap, Vec<MnemonicWord>);
+impl OnEvent for MnemonicGrid {}
+
+impl MnemonicGrid {
+    fn reveal(&mut self, revealed: bool) {
+        self.1.iter_mut().for_each(|word| word.reveal(revealed));
+    }
+}
+
+/// One numbered word, masked with a fixed number of dots so the mask doesn't give away its length.
+#[derive(Debug, Component)]
+struct MnemonicWord(Stack, OutlinedRectangle, MnemonicLabel, #[skip] String);
+impl OnEvent for MnemonicWord {}
+
+impl MnemonicWord {
+    const WIDTH: f32 = 112.0;
+    const MASK: &'static str = "•••••";
+
+    fn new(ctx: &mut Context, number: usize, word: &str) -> Self {
+        let colors = &ctx.theme.colors;
+        let background = OutlinedRectangle::new(colors.background.primary, colors.outline.secondary, 8.0, 1.0);
+        let font_size = ctx.theme.fonts.size;
+        let label = MnemonicLabel(
+            Row::new(8.0, Offset::Center, Size::Fit, Padding::default()),
+            Text::new(ctx, &format!("{number}."), TextStyle::Secondary, font_size.sm, Align::Left),
+            Text::new(ctx, Self::MASK, TextStyle::Heading, font_size.md, Align::Left)
+        );
+
+        MnemonicWord(
+            Stack(Offset::Start, Offset::Center, Size::Static(Self::WIDTH), Size::Fit, Padding(12.0, 8.0, 12.0, 8.0)),
+            background, label, word.to_string()
+        )
+    }
+
+    fn reveal(&mut self, revealed: bool) {
+        self.2.2.text().spans[0].text = match revealed {
+            true => self.3.clone(),
+            false => Self::MASK.to_string(),
+        };
+    }
+}
+
+#[derive(Debug, Component)]
+struct MnemonicLabel(Row, Text, Text);
+impl OnEvent for MnemonicLabel {}
+
+#[derive(Debug)]
+enum MnemonicAction {
+    Suggestion(String),
+    Paste,
+}
+
+/// ## Mnemonic Input
+///
+/// Entry for a seed phrase with one [`TextInput`] per word.
+///
+/// While a word is typed, the words of `wordlist` starting with it are suggested below the inputs.
+/// Words that aren't in `wordlist` are marked with [`TextInput::set_error`] once the next word is started.
+/// Pasting, or typing a space, spreads the words over the following inputs. The paste button fills
+/// in a whole phrase from the clipboard.
+///
+/// Only the words themselves are checked, not the checksum of the phrase.
+///
+/// ### Example
+/// ```rust
+/// let input = MnemonicInput::new(ctx, 12, bip39::Language::English.word_list());
+/// ```
+#[derive(Component)]
+pub struct MnemonicInput(
+    Column,
+    MnemonicFields,
+    MnemonicSuggestions,
+    Button,
+    #[skip] MnemonicState,
+    #[skip] (Sender<MnemonicAction>, Receiver<MnemonicAction>),
+);
+
+impl MnemonicInput {
+    pub fn new(ctx: &mut Context, length: usize, wordlist: &'static [&'static str]) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let inputs = (0..length).map(|i| {
+            TextInput::new(ctx, None, None, &format!("Word {}", i + 1), None, TextInput::NO_ICON, false)
+        }).collect();
+        let columns = if crate::config::IS_MOBILE {2} else {3};
+        let paste = sender.clone();
+
+        MnemonicInput(
+            Column::new(16.0, Offset::Center, Size::fill(), Padding::default()),
+            MnemonicFields(Grid::uniform(columns, 8.0), inputs),
+            MnemonicSuggestions(Wrap::new(8.0, 8.0), Vec::new()),
+            Button::secondary(ctx, Some("paste"), "Paste", None, move |_ctx: &mut Context| {let _ = paste.send(MnemonicAction::Paste);}, None),
+            MnemonicState{wordlist, invalid: vec![false; length], active: None, prefix: None},
+            (sender, receiver)
+        )
+    }
+
+    /// Returns the entered words, trimmed and in lowercase.
+    pub fn words(&mut self) -> Vec<String> {
+        self.1.1.iter_mut().map(|input| input.value().trim().to_lowercase()).collect()
+    }
+
+    /// Returns `true` when every word is entered and in the word list.
+    pub fn is_valid(&mut self) -> bool {
+        let wordlist = self.4.wordlist;
+        self.words().iter().all(|word| wordlist.contains(&word.as_str()))
+    }
+
+    /// Fills in the whitespace separated words of `phrase`. A whole phrase starts at the first input,
+    /// fewer words start at the input being typed in.
+    pub fn paste(&mut self, ctx: &mut Context, phrase: &str) {
+        let words = phrase.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>();
+        let start = match words.len() >= self.1.1.len() {
+            true => 0,
+            false => self.4.active.unwrap_or(0),
+        };
+        self.fill(ctx, start, &words);
+    }
+
+    /// Empties every input.
+    pub fn clear(&mut self, ctx: &mut Context) {
+        self.1.1.iter_mut().for_each(|input| input.value().clear());
+        ctx.trigger_event(InputEditedEvent);
+    }
+
+    /// Writes `words` into the inputs from `start` on, returning the index after the last one written.
+    fn fill(&mut self, ctx: &mut Context, start: usize, words: &[String]) -> usize {
+        let inputs = &mut self.1.1;
+        let end = (start + words.len()).min(inputs.len());
+        inputs[start.min(end)..end].iter_mut().zip(words).for_each(|(input, word)| *input.value() = word.clone());
+        ctx.trigger_event(InputEditedEvent);
+        end
+    }
+
+    /// Moves the focus to the input at `index`, if there is one.
+    fn focus(&mut self, ctx: &mut Context, index: usize) {
+        let Some(input) = self.1.1.get_mut(index) else { return; };
+        *input.status() = InputState::Focus;
+        self.4.active = Some(index);
+        ctx.trigger_event(TextInputSelect(input.get_id()));
+    }
+
+    fn tick(&mut self, ctx: &mut Context) {
+        let actions = self.5.1.try_iter().collect::<Vec<_>>();
+        for action in actions {
+            match action {
+                MnemonicAction::Paste => {
+                    let phrase = ctx.hardware.paste();
+                    self.paste(ctx, &phrase);
+                },
+                MnemonicAction::Suggestion(word) => if let Some(index) = self.4.active {
+                    let next = self.fill(ctx, index, &[word]);
+                    self.focus(ctx, next);
+                },
+            }
+        }
+
+        if let Some(index) = self.1.1.iter_mut().position(|input| *input.status() == InputState::Focus) {
+            self.4.active = Some(index);
+        }
+
+        for index in 0..self.1.1.len() {
+            let value = self.1.1[index].value().clone();
+            if !value.contains(char::is_whitespace) { continue; }
+            let words = value.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>();
+            if words.is_empty() { *self.1.1[index].value() = String::new(); }
+            let next = self.fill(ctx, index, &words);
+            if value.ends_with(char::is_whitespace) || words.len() > 1 { self.focus(ctx, next); }
+        }
+
+        let wordlist = self.4.wordlist;
+        for (index, input) in self.1.1.iter_mut().enumerate() {
+            let value = input.value().trim().to_lowercase();
+            let typing = self.4.active == Some(index) && wordlist.iter().any(|word| word.starts_with(value.as_str()));
+            let invalid = !value.is_empty() && !typing && !wordlist.contains(&value.as_str());
+            if invalid == self.4.invalid[index] { continue; }
+            self.4.invalid[index] = invalid;
+            match invalid {
+                true => input.set_error(ctx, "Invalid word"),
+                false => input.clear_error(),
+            }
+        }
+
+        let prefix = self.4.active.map(|index| self.1.1[index].value().trim().to_lowercase())
+            .filter(|value| !value.is_empty() && !wordlist.contains(&value.as_str()));
+        if prefix != self.4.prefix {
+            let words = prefix.as_deref().map(|prefix| {
+                wordlist.iter().filter(|word| word.starts_with(prefix)).take(MnemonicSuggestions::MAX).copied().collect()
+            }).unwrap_or_default();
+            self.2.set(ctx, words, &self.5.0);
+            self.4.prefix = prefix;
+        }
+    }
+}
+
+impl OnEvent for MnemonicInput {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() { self.tick(ctx); }
+        true
+    }
+}
+
+impl std::fmt::Debug for MnemonicInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MnemonicInput({} words)", self.1.1.len())
+    }
+}
+
+#[derive(Debug)]
+struct MnemonicState {
+    wordlist: &'static [&'static str],
+    invalid: Vec<bool>,
+    /// The input last typed in, kept while the focus moves to a suggestion.
+    active: Option<usize>,
+    prefix: Option<String>,
+}
+
+#[derive(Debug, Component)]
+struct MnemonicFields(Grid, Vec<TextInput>);
+impl OnEvent for MnemonicFields {}
+
+#[derive(Debug, Component)]
+struct MnemonicSuggestions(Wrap, Vec<Button>);
+impl OnEvent for MnemonicSuggestions {}
+
+impl MnemonicSuggestions {
+    const MAX: usize = 4;
+
+    fn set(&mut self, ctx: &mut Context, words: Vec<&'static str>, sender: &Sender<MnemonicAction>) {
+        self.1 = words.into_iter().map(|word| {
+            let sender = sender.clone();
+            Button::secondary(ctx, None, word, None, move |_ctx: &mut Context| {
+                let _ = sender.send(MnemonicAction::Suggestion(word.to_string()));
+            }, None)
+        }).collect();
+    }
+}
@@ -1,7 +1,7 @@
 mod button;
 pub use button::{Button, ButtonStyle, ButtonSize, ButtonState, ButtonWidth, IconButton, QuickActions};
 mod alert;
-pub use alert::Alert;
+pub use alert::{Alert, AlertStyle};
 mod avatar;
 pub use avatar::{Avatar, AvatarContent, AvatarIconStyle};
 mod data_item;
@@ -15,4 +15,31 @@ pub use qr_code::QRCode;
 mod qr_scanner;
 pub use qr_scanner::QRCodeScanner;
 mod slider;
-pub use slider::Slider;
\ No newline at end of file
+pub use slider::Slider;
+mod lazy_list;
+pub use lazy_list::LazyList;
+mod section;
+pub use section::{SectionedList, Section, SectionHeader};
+mod selection;
+pub use selection::{Toggle, Checkbox, CheckState, RadioGroup};
+mod tabs;
+pub use tabs::{SegmentedControl, TabBar, TabView};
+mod select;
+pub use select::{Select, ContextMenu};
+mod picker;
+pub use picker::{DatePicker, DateRangePicker, TimePicker, DateBounds};
+mod keypad;
+mod amount;
+pub use amount::{AmountInput, AmountUnit};
+mod passcode;
+pub use passcode::{Passcode, PasscodeMode};
+mod mnemonic;
+pub use mnemonic::{MnemonicDisplay, MnemonicInput};
+mod message;
+pub use message::{Message, MessageDirection, MessageStatus, MessageBubble, MessageList};
+mod composer;
+pub use composer::MessageComposer;
+mod chart;
+pub use chart::Chart;
+mod progress;
+pub use progress::{ProgressBar, ProgressRing, Spinner};
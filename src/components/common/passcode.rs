@@ -0,0 +1,329 @@
+use pelican_ui::{
+    Align, Area, Component, Context,
+    Drawable, Event, Layout, Shape,
+    OnEvent, SizeRequest, TickEvent,
+    KeyboardEvent, KeyboardState,
+};
+
+use crate::elements::{Circle, Text, TextStyle};
+use crate::events::KeyboardActiveEvent;
+use crate::layout::{Column, Offset, Padding, Row, Size};
+
+use super::keypad::{Keypad, KeypadKey};
+
+use std::sync::{Arc, Mutex, mpsc::{self, Receiver}};
+use std::sync::atomic::{compiler_fence, Ordering};
+use std::time::Instant;
+
+/// What a [`Passcode`] does with a passcode once all of its digits are entered.
+pub enum PasscodeMode {
+    /// Asks for a new passcode, then asks for it again and accepts it once both match.
+    Create,
+    /// Accepts the passcode when the function returns `true`.
+    Verify(Box<dyn FnMut(&[u8]) -> bool>),
+}
+
+impl std::fmt::Debug for PasscodeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasscodeMode::Create => write!(f, "Create"),
+            PasscodeMode::Verify(_) => write!(f, "Verify"),
+        }
+    }
+}
+
+/// ## Passcode
+///
+/// Entry for a numeric passcode: a row of dots filling in as digits are entered on a keypad.
+///
+/// When a passcode is rejected, or the confirmation of a new passcode doesn't match, the dots shake
+/// and an error is shown. After [`Passcode::lockout`] failed attempts the keypad is locked for a while,
+/// with a countdown in place of the error.
+///
+/// Digits are only entered on this keypad, or typed on desktop and web, never with the
+/// [`MobileKeyboard`](crate::MobileKeyboard). They are overwritten with zeros once handled and when the passcode is dropped.
+///
+/// ### Example
+/// ```rust
+/// let passcode = Passcode::new(ctx, 6, PasscodeMode::Verify(Box::new(|digits: &[u8]| digits == [1, 2, 3, 4, 5, 6])), |ctx: &mut Context, _: &[u8]| {
+///     ctx.trigger_event(NavigationEvent::Pop);
+/// }).lockout(5, 30.0);
+/// ```
+#[derive(Component)]
+pub struct Passcode(
+    Column,
+    Text,
+    PasscodeDots,
+    Option<Text>,
+    Keypad,
+    #[skip] PasscodeState,
+    #[skip] PasscodeMode,
+    #[skip] Box<dyn FnMut(&mut Context, &[u8])>,
+    #[skip] Receiver<KeypadKey>,
+);
+
+impl Passcode {
+    pub const SHAKE: f32 = 0.4;
+
+    /// Creates an entry for a passcode of `length` digits, at least one.
+    pub fn new(
+        ctx: &mut Context,
+        length: usize,
+        mode: PasscodeMode,
+        on_complete: impl FnMut(&mut Context, &[u8]) + 'static,
+    ) -> Self {
+        // An empty passcode would never complete.
+        let length = length.max(1);
+        let (sender, receiver) = mpsc::channel();
+        let font_size = ctx.theme.fonts.size.h4;
+        let prompt = match mode {
+            PasscodeMode::Create => "Create a passcode",
+            PasscodeMode::Verify(_) => "Enter your passcode",
+        };
+        let state = PasscodeState {
+            length,
+            // Enough room up front, so entering digits never moves them and leaves a copy behind.
+            digits: Vec::with_capacity(length),
+            first: None,
+            failures: 0,
+            attempts: 5,
+            lockout: 30.0,
+            locked: None,
+            shake: None,
+            started: false,
+        };
+
+        Passcode(
+            Column::new(32.0, Offset::Center, Size::fill(), Padding::default()),
+            Text::new(ctx, prompt, TextStyle::Heading, font_size, Align::Center),
+            PasscodeDots::new(ctx, length),
+            None,
+            Keypad::new(ctx, sender, false),
+            state,
+            mode,
+            Box::new(on_complete),
+            receiver
+        )
+    }
+
+    /// Locks the keypad for `seconds` after `attempts` rejected passcodes in a row.
+    pub fn lockout(mut self, attempts: u32, seconds: f32) -> Self {
+        self.5.attempts = attempts.max(1);
+        self.5.lockout = seconds;
+        self
+    }
+
+    /// Returns how many digits have been entered.
+    pub fn entered(&self) -> usize { self.5.digits.len() }
+    /// Returns `true` while the keypad is locked after too many failed attempts.
+    pub fn is_locked(&self) -> bool { self.5.locked.is_some() }
+    /// Returns `true` while asking to confirm a new passcode.
+    pub fn is_confirming(&self) -> bool { self.5.first.is_some() }
+
+    /// Shows an error under the dots and shakes them.
+    pub fn set_error(&mut self, ctx: &mut Context, error: &str) {
+        let font_size = ctx.theme.fonts.size.sm;
+        self.3 = Some(Text::new(ctx, error, TextStyle::Error, font_size, Align::Center));
+        self.5.shake = Some(Instant::now());
+    }
+
+    fn press(&mut self, ctx: &mut Context, key: KeypadKey) {
+        let state = &mut self.5;
+        if state.locked.is_some() { return; }
+        match key {
+            KeypadKey::Digit(digit) if state.digits.len() < state.length => {
+                state.digits.push(digit as u8 - b'0');
+                self.3 = None;
+            },
+            KeypadKey::Backspace => {
+                if let Some(last) = state.digits.last_mut() { zero(std::slice::from_mut(last)); }
+                state.digits.pop();
+            },
+            _ => return,
+        }
+
+        self.2.fill(ctx, self.5.digits.len(), self.5.shake.is_some());
+        if self.5.digits.len() == self.5.length { self.complete(ctx); }
+    }
+
+    fn complete(&mut self, ctx: &mut Context) {
+        let state = &mut self.5;
+        let error = match &mut self.6 {
+            PasscodeMode::Create => match state.first.take() {
+                None => {
+                    let mut first = Vec::with_capacity(state.length);
+                    first.extend_from_slice(&state.digits);
+                    state.first = Some(first);
+                    self.1.text().spans[0].text = "Confirm your passcode".to_string();
+                    None
+                },
+                Some(mut first) => {
+                    let matched = first == state.digits;
+                    zero(&mut first);
+                    match matched {
+                        true => {
+                            (self.7)(ctx, &state.digits);
+                            None
+                        },
+                        false => {
+                            self.1.text().spans[0].text = "Create a passcode".to_string();
+                            Some("Passcodes didn't match. Try again.".to_string())
+                        }
+                    }
+                },
+            },
+            PasscodeMode::Verify(check) => match check(&state.digits) {
+                true => {
+                    state.failures = 0;
+                    (self.7)(ctx, &state.digits);
+                    None
+                },
+                false => {
+                    state.failures += 1;
+                    let left = state.attempts.saturating_sub(state.failures);
+                    if left == 0 {
+                        state.locked = Some(Instant::now());
+                        self.4.set_disabled(ctx, true);
+                        Some(format!("Too many attempts. Try again in {}s.", state.lockout.ceil()))
+                    } else {
+                        let attempts = if left == 1 {"attempt"} else {"attempts"};
+                        Some(format!("Incorrect passcode. {left} {attempts} left."))
+                    }
+                },
+            },
+        };
+
+        zero(&mut self.5.digits);
+        self.5.digits.clear();
+        if let Some(error) = error { self.set_error(ctx, &error); }
+        self.2.fill(ctx, 0, self.5.shake.is_some());
+    }
+
+    fn tick(&mut self, ctx: &mut Context) {
+        let state = &mut self.5;
+        if let Some(locked) = state.locked {
+            let remaining = state.lockout - locked.elapsed().as_secs_f32();
+            if remaining <= 0.0 {
+                state.locked = None;
+                state.failures = 0;
+                self.3 = None;
+                self.4.set_disabled(ctx, false);
+            } else if let Some(error) = self.3.as_mut() {
+                error.text().spans[0].text = format!("Too many attempts. Try again in {}s.", remaining.ceil());
+            }
+        }
+
+        let shake = if crate::config::reduce_motion() {0.0} else {Self::SHAKE};
+        let offset = match self.5.shake.map(|start| start.elapsed().as_secs_f32() / shake) {
+            Some(t) if t < 1.0 => (t * std::f32::consts::TAU * 4.0).sin() * 12.0 * (1.0 - t),
+            _ => {
+                self.5.shake = None;
+                0.0
+            },
+        };
+        self.2.shake(offset);
+        self.2.fill(ctx, self.5.digits.len(), self.5.shake.is_some());
+    }
+}
+
+impl OnEvent for Passcode {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            if !self.5.started {
+                // Hide a keyboard left open by the previous page, the keypad is used instead.
+                self.5.started = true;
+                ctx.trigger_event(KeyboardActiveEvent(None));
+            }
+            let keys = self.8.try_iter().collect::<Vec<_>>();
+            keys.into_iter().for_each(|key| self.press(ctx, key));
+            self.tick(ctx);
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key}) = event.downcast_ref::<KeyboardEvent>() {
+            if crate::config::IS_MOBILE { return true; }
+            if let Some(key) = KeypadKey::from_key(key) { self.press(ctx, key); }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for Passcode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Passcode({}/{}, {:?})", self.5.digits.len(), self.5.length, self.6)
+    }
+}
+
+struct PasscodeState {
+    length: usize,
+    digits: Vec<u8>,
+    /// The new passcode while it is being confirmed.
+    first: Option<Vec<u8>>,
+    failures: u32,
+    attempts: u32,
+    /// Seconds the keypad stays locked.
+    lockout: f32,
+    locked: Option<Instant>,
+    shake: Option<Instant>,
+    started: bool,
+}
+
+impl Drop for PasscodeState {
+    fn drop(&mut self) {
+        zero(&mut self.digits);
+        if let Some(first) = self.first.as_mut() { zero(first); }
+    }
+}
+
+/// Overwrites `digits` with zeros in a way the compiler can't optimize out.
+fn zero(digits: &mut [u8]) {
+    digits.iter_mut().for_each(|digit| {
+        // SAFETY: `digit` is a valid, aligned and exclusive reference.
+        unsafe { std::ptr::write_volatile(digit, 0); }
+    });
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// One dot per digit, filled in as digits are entered.
+#[derive(Debug, Component)]
+struct PasscodeDots(ShakeLayout, Vec<Shape>);
+impl OnEvent for PasscodeDots {}
+
+impl PasscodeDots {
+    const SIZE: f32 = 16.0;
+
+    fn new(ctx: &mut Context, length: usize) -> Self {
+        let color = ctx.theme.colors.outline.secondary;
+        let layout = ShakeLayout(Row::new(16.0, Offset::Center, Size::Fit, Padding::default()), Arc::new(Mutex::new(0.0)));
+        PasscodeDots(layout, (0..length).map(|_| Circle::new(Self::SIZE, color)).collect())
+    }
+
+    /// Fills in the first `entered` dots, or shows every dot in the error color while `shaking`.
+    fn fill(&mut self, ctx: &mut Context, entered: usize, shaking: bool) {
+        let colors = &ctx.theme.colors;
+        self.1.iter_mut().enumerate().for_each(|(i, dot)| {
+            dot.color = match shaking {
+                true => colors.status.danger,
+                false if i < entered => colors.text.heading,
+                false => colors.outline.secondary,
+            };
+        });
+    }
+
+    /// Moves the dots sideways by `offset`.
+    fn shake(&mut self, offset: f32) {
+        *self.0.1.lock().unwrap() = offset;
+    }
+}
+
+/// A [`Row`] moved sideways by a shared offset.
+#[derive(Debug)]
+struct ShakeLayout(Row, Arc<Mutex<f32>>);
+
+impl Layout for ShakeLayout {
+    fn request_size(&self, ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        self.0.request_size(ctx, children)
+    }
+
+    fn build(&self, ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let offset = *self.1.lock().unwrap();
+        self.0.build(ctx, size, children).into_iter().map(|area| Area{offset: (area.offset.0 + offset, area.offset.1), ..area}).collect()
+    }
+}
@@ -0,0 +1,650 @@
+use pelican_ui::{
+    Align, Area, Component, Context,
+    Drawable, Event, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+};
+use chrono::{DateTime, Datelike, Local, Months, NaiveDate, NaiveTime, TimeZone, Timelike};
+
+use crate::elements::{OutlinedRectangle, Text, TextStyle};
+use crate::events::{DismissModalEvent, PresentModalEvent, SetInputValue};
+use crate::components::{Button, ButtonSize, ButtonState, ButtonStyle, ButtonWidth, IconButton, Modal, ModalResult};
+use crate::layout::{Bin, Column, Grid, Offset, Padding, Row, Scroll, ScrollAnchor, Size, Stack};
+use crate::utils::{ElementID, Timestamp};
+
+use std::sync::{Arc, Mutex, mpsc::{self, Receiver, Sender}};
+
+/// Limits on what a [`DatePicker`], [`DateRangePicker`] or [`TimePicker`] lets the user pick.
+///
+/// ```rust
+/// let bounds = DateBounds::new()
+///     .min(Local::now())
+///     .disable(|date: NaiveDate| date.weekday() == Weekday::Sun);
+/// ```
+#[derive(Clone, Default)]
+pub struct DateBounds {
+    min: Option<DateTime<Local>>,
+    max: Option<DateTime<Local>>,
+    disabled: Option<Arc<dyn Fn(NaiveDate) -> bool>>,
+}
+
+impl DateBounds {
+    pub fn new() -> Self { Self::default() }
+
+    /// Sets the earliest date and time that can be picked.
+    pub fn min(mut self, min: DateTime<Local>) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest date and time that can be picked.
+    pub fn max(mut self, max: DateTime<Local>) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Disables the days for which `disabled` returns `true`, like weekends or holidays.
+    pub fn disable(mut self, disabled: impl Fn(NaiveDate) -> bool + 'static) -> Self {
+        self.disabled = Some(Arc::new(disabled));
+        self
+    }
+
+    /// Returns `true` if the day can be picked.
+    pub fn allows_date(&self, date: NaiveDate) -> bool {
+        self.min.is_none_or(|min| date >= min.date_naive())
+            && self.max.is_none_or(|max| date <= max.date_naive())
+            && !self.disabled.as_ref().is_some_and(|disabled| disabled(date))
+    }
+
+    /// Returns `true` if the date and time can be picked.
+    pub fn allows(&self, time: DateTime<Local>) -> bool {
+        self.allows_date(time.date_naive())
+            && self.min.is_none_or(|min| time >= min)
+            && self.max.is_none_or(|max| time <= max)
+    }
+}
+
+impl std::fmt::Debug for DateBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DateBounds({:?}, {:?})", self.min, self.max)
+    }
+}
+
+/// ## Date Picker
+///
+/// A month calendar for picking one day. The arrows above the calendar move between months
+/// without leaving the [`DateBounds`]. Days outside the bounds are disabled and today is outlined.
+///
+/// The picked day is passed to `on_pick` as the start of that day in local time.
+/// [`DatePicker::action`] opens the picker from the icon button of a [`TextInput`](crate::TextInput).
+///
+/// ### Example
+/// ```rust
+/// let bounds = DateBounds::new().min(Local::now());
+/// let picker = DatePicker::new(ctx, None, bounds, |ctx: &mut Context, date: DateTime<Local>| {
+///     println!("Sending on {}", Timestamp::new(date).date());
+/// });
+/// ```
+#[derive(Component)]
+pub struct DatePicker(Stack, Calendar, #[skip] Box<dyn FnMut(&mut Context, DateTime<Local>)>);
+
+impl DatePicker {
+    pub fn new(
+        ctx: &mut Context,
+        selected: Option<DateTime<Local>>,
+        bounds: DateBounds,
+        on_pick: impl FnMut(&mut Context, DateTime<Local>) + 'static,
+    ) -> Self {
+        let selected = selected.map(|s| s.date_naive());
+        DatePicker(Stack::default(), Calendar::new(ctx, selected, Picked::Day(selected), bounds), Box::new(on_pick))
+    }
+
+    /// Returns the picked day.
+    pub fn selected(&self) -> Option<DateTime<Local>> {
+        match self.1.4 {
+            Picked::Day(day) => day.map(start_of),
+            Picked::Range(..) => None,
+        }
+    }
+
+    /// Picks `date` and shows its month, without calling `on_pick`.
+    pub fn select(&mut self, ctx: &mut Context, date: Option<DateTime<Local>>) {
+        let date = date.map(|d| d.date_naive());
+        self.1.set(ctx, date, Picked::Day(date));
+    }
+
+    /// An icon button action for a [`TextInput`](crate::TextInput) that opens a date picker in a sheet.
+    /// The picked day is written into the input with the [`ElementID`] `input`, and passed to `on_pick`.
+    ///
+    /// ```rust
+    /// let mut input = TextInput::new(ctx, None, Some("Date"), "MM/DD/YY", None, TextInput::NO_ICON, false);
+    /// let action = DatePicker::action(input.get_id(), DateBounds::new(), |ctx: &mut Context, date: DateTime<Local>| {
+    ///     println!("Picked {date}");
+    /// });
+    /// input.set_icon_button(ctx, "down", action);
+    /// ```
+    pub fn action(
+        input: ElementID,
+        bounds: DateBounds,
+        on_pick: impl FnMut(&mut Context, DateTime<Local>) + 'static,
+    ) -> impl FnMut(&mut Context, &mut String) {
+        let on_pick = Arc::new(Mutex::new(on_pick));
+        move |ctx: &mut Context, value: &mut String| {
+            let selected = parse_date(value).map(start_of);
+            let on_pick = on_pick.clone();
+            let picker = DatePicker::new(ctx, selected, bounds.clone(), move |ctx: &mut Context, date: DateTime<Local>| {
+                ctx.trigger_event(SetInputValue(input, Timestamp::new(date).date()));
+                ctx.trigger_event(DismissModalEvent(ModalResult::Primary));
+                let mut on_pick = on_pick.lock().unwrap();
+                (*on_pick)(ctx, date);
+            });
+            present(ctx, Box::new(picker));
+        }
+    }
+}
+
+impl OnEvent for DatePicker {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() && self.1.changed() {
+            if let Some(date) = self.selected() { (self.2)(ctx, date); }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for DatePicker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DatePicker({:?})", self.1.4)
+    }
+}
+
+/// ## Date Range Picker
+///
+/// A month calendar for picking a range of days. The first press picks the first day of the range
+/// and the second press its last day. Pressing a day before the first day starts the range over.
+///
+/// Once both days are picked, `on_pick` is given the start of the first day and the end of the last day in local time.
+///
+/// ### Example
+/// ```rust
+/// let picker = DateRangePicker::new(ctx, None, DateBounds::new().max(Local::now()), |ctx: &mut Context, from: DateTime<Local>, to: DateTime<Local>| {
+///     println!("Showing transactions from {from} to {to}");
+/// });
+/// ```
+#[derive(Component)]
+pub struct DateRangePicker(Stack, Calendar, #[skip] Box<dyn FnMut(&mut Context, DateTime<Local>, DateTime<Local>)>);
+
+impl DateRangePicker {
+    pub fn new(
+        ctx: &mut Context,
+        selected: Option<(DateTime<Local>, DateTime<Local>)>,
+        bounds: DateBounds,
+        on_pick: impl FnMut(&mut Context, DateTime<Local>, DateTime<Local>) + 'static,
+    ) -> Self {
+        let (from, to) = selected.map(|(f, t)| (Some(f.date_naive()), Some(t.date_naive()))).unwrap_or((None, None));
+        DateRangePicker(Stack::default(), Calendar::new(ctx, from, Picked::Range(from, to), bounds), Box::new(on_pick))
+    }
+
+    /// Returns the picked range once both of its days are picked.
+    pub fn selected(&self) -> Option<(DateTime<Local>, DateTime<Local>)> {
+        match self.1.4 {
+            Picked::Range(Some(from), Some(to)) => Some((start_of(from), end_of(to))),
+            _ => None,
+        }
+    }
+
+    /// Picks the range and shows the month of its first day, without calling `on_pick`.
+    pub fn select(&mut self, ctx: &mut Context, range: Option<(DateTime<Local>, DateTime<Local>)>) {
+        let (from, to) = range.map(|(f, t)| (Some(f.date_naive()), Some(t.date_naive()))).unwrap_or((None, None));
+        self.1.set(ctx, from, Picked::Range(from, to));
+    }
+
+    /// An icon button action for a [`TextInput`](crate::TextInput) that opens a date range picker in a sheet.
+    /// The picked range is written into the input with the [`ElementID`] `input`, and passed to `on_pick`.
+    pub fn action(
+        input: ElementID,
+        bounds: DateBounds,
+        on_pick: impl FnMut(&mut Context, DateTime<Local>, DateTime<Local>) + 'static,
+    ) -> impl FnMut(&mut Context, &mut String) {
+        let on_pick = Arc::new(Mutex::new(on_pick));
+        move |ctx: &mut Context, value: &mut String| {
+            let selected = value.split_once(" - ").and_then(|(from, to)| Some((start_of(parse_date(from)?), end_of(parse_date(to)?))));
+            let on_pick = on_pick.clone();
+            let picker = DateRangePicker::new(ctx, selected, bounds.clone(), move |ctx: &mut Context, from: DateTime<Local>, to: DateTime<Local>| {
+                let value = format!("{} - {}", Timestamp::new(from).date(), Timestamp::new(to).date());
+                ctx.trigger_event(SetInputValue(input, value));
+                ctx.trigger_event(DismissModalEvent(ModalResult::Primary));
+                let mut on_pick = on_pick.lock().unwrap();
+                (*on_pick)(ctx, from, to);
+            });
+            present(ctx, Box::new(picker));
+        }
+    }
+}
+
+impl OnEvent for DateRangePicker {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() && self.1.changed() {
+            if let Some((from, to)) = self.selected() { (self.2)(ctx, from, to); }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for DateRangePicker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DateRangePicker({:?})", self.1.4)
+    }
+}
+
+/// ## Time Picker
+///
+/// A scrolling list of the times of one day, `step` minutes apart, for picking a time.
+/// Times outside the [`DateBounds`] are disabled. The list starts scrolled to the picked time.
+///
+/// The picked time is passed to `on_pick` on the day of `selected`, or today when nothing is picked yet.
+///
+/// ### Example
+/// ```rust
+/// let picker = TimePicker::new(ctx, None, 15, DateBounds::new().min(Local::now()), |ctx: &mut Context, time: DateTime<Local>| {
+///     println!("Reminder set for {}", Timestamp::new(time).time());
+/// });
+/// ```
+#[derive(Component)]
+pub struct TimePicker(
+    Stack,
+    TimeList,
+    #[skip] NaiveDate,
+    #[skip] Vec<NaiveTime>,
+    #[skip] Option<usize>,
+    #[skip] DateBounds,
+    #[skip] Box<dyn FnMut(&mut Context, DateTime<Local>)>,
+    #[skip] (Sender<usize>, Receiver<usize>),
+    #[skip] bool,
+);
+
+impl TimePicker {
+    pub fn new(
+        ctx: &mut Context,
+        selected: Option<DateTime<Local>>,
+        step: u32,
+        bounds: DateBounds,
+        on_pick: impl FnMut(&mut Context, DateTime<Local>) + 'static,
+    ) -> Self {
+        let step = step.clamp(1, 60) as usize;
+        let times = (0..24 * 60).step_by(step).filter_map(|m| NaiveTime::from_hms_opt(m / 60, m % 60, 0)).collect::<Vec<_>>();
+        let index = selected.and_then(|s| times.iter().position(|t| t.hour() == s.hour() && t.minute() == s.minute()));
+        let day = selected.map(|s| s.date_naive()).unwrap_or_else(|| Local::now().date_naive());
+        let (sender, receiver) = mpsc::channel();
+
+        let mut picker = TimePicker(Stack::default(), TimeList::new(Vec::new()), day, times, index, bounds, Box::new(on_pick), (sender, receiver), false);
+        picker.build(ctx);
+        picker
+    }
+
+    /// Returns the picked time.
+    pub fn selected(&self) -> Option<DateTime<Local>> {
+        self.4.map(|i| at(self.2, self.3[i]))
+    }
+
+    /// Changes the day the times are picked on, disabling the times the [`DateBounds`] don't allow on that day.
+    pub fn set_date(&mut self, ctx: &mut Context, date: NaiveDate) {
+        self.2 = date;
+        self.refresh(ctx);
+    }
+
+    /// An icon button action for a [`TextInput`](crate::TextInput) that opens a time picker in a sheet.
+    /// The picked time is written into the input with the [`ElementID`] `input`, and passed to `on_pick`.
+    pub fn action(
+        input: ElementID,
+        step: u32,
+        bounds: DateBounds,
+        on_pick: impl FnMut(&mut Context, DateTime<Local>) + 'static,
+    ) -> impl FnMut(&mut Context, &mut String) {
+        let on_pick = Arc::new(Mutex::new(on_pick));
+        move |ctx: &mut Context, value: &mut String| {
+            let selected = NaiveTime::parse_from_str(value.trim(), "%I:%M %p").ok().map(|t| at(Local::now().date_naive(), t));
+            let on_pick = on_pick.clone();
+            let picker = TimePicker::new(ctx, selected, step, bounds.clone(), move |ctx: &mut Context, time: DateTime<Local>| {
+                ctx.trigger_event(SetInputValue(input, Timestamp::new(time).time()));
+                ctx.trigger_event(DismissModalEvent(ModalResult::Primary));
+                let mut on_pick = on_pick.lock().unwrap();
+                (*on_pick)(ctx, time);
+            });
+            present(ctx, Box::new(picker));
+        }
+    }
+
+    fn build(&mut self, ctx: &mut Context) {
+        let rows = self.3.iter().enumerate().map(|(i, time)| {
+            let sender = self.7.0.clone();
+            let label = Timestamp::new(at(self.2, *time)).time();
+            Button::new(
+                ctx, None, None, Some(&label), None, ButtonSize::Medium, ButtonWidth::Expand,
+                ButtonStyle::Ghost, self.state(i), Offset::Center, move |_ctx: &mut Context| {let _ = sender.send(i);}, None
+            )
+        }).collect();
+        self.1 = TimeList::new(rows);
+        self.8 = false;
+    }
+
+    /// Updates the states of the times in place, keeping the list where it is scrolled.
+    fn refresh(&mut self, ctx: &mut Context) {
+        let states = (0..self.3.len()).map(|i| self.state(i)).collect::<Vec<_>>();
+        self.1.1.iter_mut().zip(states).for_each(|(row, state)| {
+            *row.status() = state;
+            row.color(ctx);
+        });
+    }
+
+    fn state(&self, i: usize) -> ButtonState {
+        match self.5.allows(at(self.2, self.3[i])) {
+            false => ButtonState::Disabled,
+            true if self.4 == Some(i) => ButtonState::Selected,
+            true => ButtonState::Default,
+        }
+    }
+}
+
+impl OnEvent for TimePicker {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            if let Some(index) = self.7.1.try_iter().last() {
+                self.4 = Some(index);
+                self.refresh(ctx);
+                let time = at(self.2, self.3[index]);
+                (self.6)(ctx, time);
+            }
+
+            // Scroll to the picked time once the list has been laid out.
+            let scroll = &mut self.1.0;
+            if !self.8 && scroll.max_offset() > 0.0 {
+                self.8 = true;
+                if let Some(index) = self.4 {
+                    let height = ButtonSize::Medium.sizes().0;
+                    scroll.scroll_into_view((index as f32 * height) - scroll.scroll_offset(), height, Offset::Center, false);
+                }
+            }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for TimePicker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TimePicker({:?})", self.selected())
+    }
+}
+
+/// The times of a [`TimePicker`], scrolling once they are taller than [`TimeList::MAX_HEIGHT`].
+#[derive(Debug, Component)]
+struct TimeList(Scroll, Vec<Button>);
+
+impl TimeList {
+    const MAX_HEIGHT: f32 = 280.0;
+
+    fn new(rows: Vec<Button>) -> Self {
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths.iter().map(|w| w.0).fold(0.0, f32::max), f32::MAX));
+        let height = Size::custom(move |heights: Vec<(f32, f32)>|{
+            let total = heights.iter().map(|h| h.0).sum::<f32>();
+            (total.min(Self::MAX_HEIGHT), total.min(Self::MAX_HEIGHT))
+        });
+        TimeList(Scroll::new(Offset::Start, Offset::Start, width, height, Padding::default(), ScrollAnchor::Start), rows)
+    }
+}
+
+impl OnEvent for TimeList {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(MouseEvent { state: MouseState::Scroll(_, y), position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.adjust_scroll(*y);
+        } else if let Some(MouseEvent { state: MouseState::Pressed, position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.hold();
+        } else if let Some(MouseEvent { state: MouseState::Released, .. }) = event.downcast_ref::<MouseEvent>() {
+            self.0.release();
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            self.0.tick();
+        }
+        true
+    }
+}
+
+/// Which days of a [`Calendar`] are picked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Picked {
+    Day(Option<NaiveDate>),
+    Range(Option<NaiveDate>, Option<NaiveDate>),
+}
+
+impl Picked {
+    fn pick(self, date: NaiveDate) -> Self {
+        match self {
+            Picked::Day(_) => Picked::Day(Some(date)),
+            Picked::Range(Some(from), None) if date >= from => Picked::Range(Some(from), Some(date)),
+            Picked::Range(..) => Picked::Range(Some(date), None),
+        }
+    }
+
+    fn marks(&self, date: NaiveDate) -> (bool, bool) {
+        match *self {
+            Picked::Day(day) => (day == Some(date), false),
+            Picked::Range(from, to) => (
+                from == Some(date) || to == Some(date),
+                from.zip(to).is_some_and(|(from, to)| date > from && date < to)
+            ),
+        }
+    }
+}
+
+/// The month calendar shared by the [`DatePicker`] and the [`DateRangePicker`].
+#[derive(Debug, Component)]
+struct Calendar(
+    Column,
+    CalendarHeader,
+    DayGrid,
+    #[skip] NaiveDate,
+    #[skip] Picked,
+    #[skip] DateBounds,
+    #[skip] Receiver<i32>,
+    #[skip] (Sender<NaiveDate>, Receiver<NaiveDate>),
+    #[skip] bool,
+);
+
+impl Calendar {
+    fn new(ctx: &mut Context, month: Option<NaiveDate>, picked: Picked, bounds: DateBounds) -> Self {
+        let month = first_of(month.unwrap_or_else(|| Local::now().date_naive()));
+        let (nav, receiver) = mpsc::channel();
+        let (sender, picks) = mpsc::channel();
+        let days = DayGrid::new(ctx, month, picked, &bounds, sender.clone());
+        Calendar(
+            Column::new(8.0, Offset::Center, Size::Fit, Padding::new(8.0)),
+            CalendarHeader::new(ctx, month, nav),
+            days, month, picked, bounds, receiver, (sender, picks), false
+        )
+    }
+
+    /// Changes the picked days and shows the month of `month`.
+    fn set(&mut self, ctx: &mut Context, month: Option<NaiveDate>, picked: Picked) {
+        self.4 = picked;
+        if let Some(month) = month { self.3 = first_of(month); }
+        self.build(ctx);
+    }
+
+    /// Returns `true` if a day was picked since the last call.
+    fn changed(&mut self) -> bool {
+        std::mem::take(&mut self.8)
+    }
+
+    /// Moves `step` months forward or back, staying within the bounds.
+    fn shift(&mut self, step: i32) {
+        let month = match step < 0 {
+            true => self.3.checked_sub_months(Months::new(step.unsigned_abs())),
+            false => self.3.checked_add_months(Months::new(step as u32)),
+        };
+        let Some(month) = month else { return; };
+        let before = self.5.min.is_some_and(|min| month < first_of(min.date_naive()));
+        let after = self.5.max.is_some_and(|max| month > first_of(max.date_naive()));
+        if !before && !after { self.3 = month; }
+    }
+
+    fn build(&mut self, ctx: &mut Context) {
+        self.1.set_title(ctx, self.3);
+        self.2 = DayGrid::new(ctx, self.3, self.4, &self.5, self.7.0.clone());
+    }
+}
+
+impl OnEvent for Calendar {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            let step = self.6.try_iter().sum::<i32>();
+            let picked = self.7.1.try_iter().last();
+            if step != 0 { self.shift(step); }
+            if let Some(date) = picked {
+                self.4 = self.4.pick(date);
+                self.8 = true;
+            }
+            if step != 0 || picked.is_some() { self.build(ctx); }
+        }
+        true
+    }
+}
+
+/// The month title of a [`Calendar`] between the buttons moving to the previous and next month.
+#[derive(Debug, Component)]
+struct CalendarHeader(Row, IconButton, Bin<Stack, Text>, IconButton);
+impl OnEvent for CalendarHeader {}
+
+impl CalendarHeader {
+    fn new(ctx: &mut Context, month: NaiveDate, nav: Sender<i32>) -> Self {
+        let font_size = ctx.theme.fonts.size.h5;
+        let next = nav.clone();
+        CalendarHeader(
+            Row::new(8.0, Offset::Center, Size::Fit, Padding::default()),
+            IconButton::ghost_medium(ctx, "left", Box::new(move |_: &mut Context| {let _ = nav.send(-1);})),
+            Bin(
+                Stack(Offset::Center, Offset::Center, Size::fill(), Size::Fit, Padding::default()),
+                Text::new(ctx, &month.format("%B %Y").to_string(), TextStyle::Heading, font_size, Align::Center)
+            ),
+            IconButton::ghost_medium(ctx, "right", Box::new(move |_: &mut Context| {let _ = next.send(1);})),
+        )
+    }
+
+    fn set_title(&mut self, _ctx: &mut Context, month: NaiveDate) {
+        self.2.inner().text().spans[0].text = month.format("%B %Y").to_string();
+    }
+}
+
+/// The weekday labels and the days of one month, starting on Sunday.
+#[derive(Debug, Component)]
+struct DayGrid(Grid, Vec<Text>, Vec<DayCell>);
+impl OnEvent for DayGrid {}
+
+impl DayGrid {
+    const WEEKDAYS: [&'static str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
+
+    fn new(ctx: &mut Context, month: NaiveDate, picked: Picked, bounds: &DateBounds, sender: Sender<NaiveDate>) -> Self {
+        let font_size = ctx.theme.fonts.size.sm;
+        let weekdays = Self::WEEKDAYS.iter().map(|day| Text::new(ctx, day, TextStyle::Secondary, font_size, Align::Center)).collect();
+
+        let today = Local::now().date_naive();
+        let blanks = (0..month.weekday().num_days_from_sunday()).map(|_| None);
+        let days = month.iter_days().take_while(|d| d.month() == month.month()).map(Some);
+        let cells = blanks.chain(days).map(|date| {
+            let (selected, in_range) = date.map(|d| picked.marks(d)).unwrap_or_default();
+            let marks = DayMarks {
+                selected,
+                in_range,
+                today: date == Some(today),
+                disabled: !date.is_some_and(|d| bounds.allows_date(d)),
+            };
+            DayCell::new(ctx, date, marks, sender.clone())
+        }).collect();
+
+        DayGrid(Grid::uniform(7, 4.0), weekdays, cells)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct DayMarks {
+    selected: bool,
+    in_range: bool,
+    today: bool,
+    disabled: bool,
+}
+
+/// One day of a [`Calendar`], or an empty cell before the first day of the month.
+#[derive(Debug, Component)]
+struct DayCell(Stack, OutlinedRectangle, Text, #[skip] Option<NaiveDate>, #[skip] DayMarks, #[skip] ButtonState, #[skip] Sender<NaiveDate>);
+
+impl DayCell {
+    const SIZE: f32 = 40.0;
+
+    fn new(ctx: &mut Context, date: Option<NaiveDate>, marks: DayMarks, sender: Sender<NaiveDate>) -> Self {
+        let font_size = ctx.theme.fonts.size.md;
+        let transparent = ctx.theme.colors.shades.transparent;
+        let label = date.map(|d| d.day().to_string()).unwrap_or_default();
+        let state = if marks.disabled {ButtonState::Disabled} else {ButtonState::Default};
+        let mut cell = DayCell(
+            Stack(Offset::Center, Offset::Center, Size::Static(Self::SIZE), Size::Static(Self::SIZE), Padding::default()),
+            OutlinedRectangle::new(transparent, transparent, Self::SIZE / 2.0, 1.0),
+            Text::new(ctx, &label, TextStyle::Primary, font_size, Align::Center),
+            date, marks, state, sender
+        );
+        cell.color(ctx);
+        cell
+    }
+
+    fn color(&mut self, ctx: &mut Context) {
+        let marks = self.4;
+        let style = if marks.selected {ButtonStyle::Primary} else {ButtonStyle::Ghost};
+        let scheme = self.5.color(ctx, style);
+        let colors = &ctx.theme.colors;
+        let in_range = marks.in_range && self.5 == ButtonState::Default;
+        *self.1.background() = if in_range {colors.background.secondary} else {scheme.background};
+        *self.1.outline() = if marks.today && !marks.selected {colors.outline.primary} else {scheme.outline};
+        self.2.text().spans[0].color = scheme.label;
+    }
+}
+
+impl OnEvent for DayCell {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            let pressed = self.5 == ButtonState::Pressed;
+            if self.5.handle(ctx, *event).is_some() { self.color(ctx); }
+            let released = pressed && matches!(event, MouseEvent{state: MouseState::Released, position: Some(_)});
+            if let (true, Some(date)) = (released, self.3) {
+                ctx.hardware.haptic();
+                let _ = self.6.send(date);
+            }
+        }
+        true
+    }
+}
+
+/// Shows a picker opened by a [`TextInput`](crate::TextInput) action in a sheet.
+fn present(ctx: &mut Context, picker: Box<dyn Drawable>) {
+    let modal = Modal::sheet(ctx, vec![picker], |_: &mut Context, _: ModalResult| {});
+    ctx.trigger_event(PresentModalEvent::new(modal));
+}
+
+/// Reads a date written by [`Timestamp::date`].
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%m/%d/%y").ok()
+}
+
+fn first_of(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// The `time` on `date` in local time.
+fn at(date: NaiveDate, time: NaiveTime) -> DateTime<Local> {
+    let naive = date.and_time(time);
+    Local.from_local_datetime(&naive).earliest().unwrap_or_else(|| Local.from_utc_datetime(&naive))
+}
+
+fn start_of(date: NaiveDate) -> DateTime<Local> {
+    at(date, NaiveTime::MIN)
+}
+
+fn end_of(date: NaiveDate) -> DateTime<Local> {
+    at(date, NaiveTime::from_hms_opt(23, 59, 59).unwrap_or(NaiveTime::MIN))
+}
@@ -0,0 +1,230 @@
+use pelican_ui::{
+    Area, Color, Component, Context,
+    Drawable, Event, Layout, Shape,
+    OnEvent, SizeRequest, TickEvent,
+};
+
+use crate::elements::{Circle, RoundedRectangle};
+use crate::layout::Size;
+
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// ## Progress Bar
+///
+/// A horizontal bar filling up in the brand color as progress is made, from `0.0` to `1.0`.
+/// Changes to the progress are animated.
+///
+/// ### Example
+/// ```rust
+/// let mut bar = ProgressBar::new(ctx, 0.0).width(200.0);
+/// bar.set_progress(0.5);
+/// ```
+#[derive(Debug, Component)]
+pub struct ProgressBar(BarLayout, RoundedRectangle, RoundedRectangle, #[skip] Progress);
+
+impl ProgressBar {
+    pub const HEIGHT: f32 = 6.0;
+
+    pub fn new(ctx: &mut Context, progress: f32) -> Self {
+        let colors = &ctx.theme.colors;
+        let progress = Progress::new(progress);
+        ProgressBar(
+            BarLayout(Size::fill(), Arc::new(Mutex::new(progress.target))),
+            RoundedRectangle::new(0.0, Self::HEIGHT / 2.0, colors.outline.secondary),
+            RoundedRectangle::new(0.0, Self::HEIGHT / 2.0, colors.brand.primary),
+            progress
+        )
+    }
+
+    /// Sets the width of the bar, which otherwise fills the available space.
+    pub fn width(mut self, width: f32) -> Self {
+        self.0.0 = Size::Static(width);
+        self
+    }
+
+    /// Returns the progress, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 { self.3.target }
+
+    /// Sets the progress, from `0.0` to `1.0`.
+    pub fn set_progress(&mut self, progress: f32) { self.3.set(progress); }
+}
+
+impl OnEvent for ProgressBar {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            *self.0.1.lock().unwrap() = self.3.shown();
+        }
+        true
+    }
+}
+
+/// Sizes the fill of a [`ProgressBar`] to the shown progress.
+#[derive(Debug)]
+struct BarLayout(Size, Arc<Mutex<f32>>);
+
+impl Layout for BarLayout {
+    fn request_size(&self, _ctx: &mut Context, _children: Vec<SizeRequest>) -> SizeRequest {
+        let (min, max) = self.0.get(vec![], Size::max);
+        SizeRequest::new(min, ProgressBar::HEIGHT, max, ProgressBar::HEIGHT)
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), _children: Vec<SizeRequest>) -> Vec<Area> {
+        let progress = *self.1.lock().unwrap();
+        // Never narrower than it is tall once started, so the rounded ends stay round.
+        let fill = if progress > 0.0 {(size.0 * progress).max(size.1).min(size.0)} else {0.0};
+        vec![
+            Area{offset: (0.0, 0.0), size},
+            Area{offset: (0.0, 0.0), size: (fill, size.1)},
+        ]
+    }
+}
+
+/// ## Progress Ring
+///
+/// A ring filling up clockwise from the top in the brand color as progress is made, from `0.0` to `1.0`.
+/// Changes to the progress are animated.
+///
+/// ### Example
+/// ```rust
+/// let mut ring = ProgressRing::new(ctx, 0.0, 48.0);
+/// ring.set_progress(0.75);
+/// ```
+#[derive(Debug, Component)]
+pub struct ProgressRing(RingLayout, Vec<Shape>, #[skip] Progress);
+
+impl ProgressRing {
+    pub fn new(ctx: &mut Context, progress: f32, size: f32) -> Self {
+        let stroke = (size * 0.1).max(2.0);
+        // Enough dots overlapping to read as one line.
+        let count = (std::f32::consts::PI * (size - stroke) / (stroke * 0.4)).ceil().max(12.0) as usize;
+        let color = ctx.theme.colors.outline.secondary;
+        let mut ring = ProgressRing(RingLayout(size), (0..count).map(|_| Circle::new(stroke, color)).collect(), Progress::new(progress));
+        ring.fill(ctx);
+        ring
+    }
+
+    /// Returns the progress, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 { self.2.target }
+
+    /// Sets the progress, from `0.0` to `1.0`.
+    pub fn set_progress(&mut self, progress: f32) { self.2.set(progress); }
+
+    fn fill(&mut self, ctx: &mut Context) {
+        let colors = &ctx.theme.colors;
+        let filled = (self.2.shown() * self.1.len() as f32).round() as usize;
+        self.1.iter_mut().enumerate().for_each(|(i, dot)| {
+            dot.color = if i < filled {colors.brand.primary} else {colors.outline.secondary};
+        });
+    }
+}
+
+impl OnEvent for ProgressRing {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            self.fill(ctx);
+        }
+        true
+    }
+}
+
+/// ## Spinner
+///
+/// A ring of dots with a tail going around in the brand color, for progress that can't be measured.
+///
+/// ### Example
+/// ```rust
+/// let spinner = Spinner::new(ctx, 32.0);
+/// ```
+#[derive(Debug, Component)]
+pub struct Spinner(RingLayout, Vec<Shape>, #[skip] Instant);
+
+impl Spinner {
+    /// Seconds for the tail to go around once.
+    pub const PERIOD: f32 = 1.0;
+    const DOTS: usize = 12;
+
+    pub fn new(ctx: &mut Context, size: f32) -> Self {
+        let color = ctx.theme.colors.brand.primary;
+        let dot = (size * 0.16).max(3.0);
+        let mut spinner = Spinner(RingLayout(size), (0..Self::DOTS).map(|_| Circle::new(dot, color)).collect(), Instant::now());
+        spinner.spin(ctx);
+        spinner
+    }
+
+    fn spin(&mut self, ctx: &mut Context) {
+        let Color(r, g, b, a) = ctx.theme.colors.brand.primary;
+        let turns = self.2.elapsed().as_secs_f32() / Self::PERIOD;
+        let head = (turns.fract() * Self::DOTS as f32) as usize;
+        self.1.iter_mut().enumerate().for_each(|(i, dot)| {
+            let behind = (head + Self::DOTS - i) % Self::DOTS;
+            let fade = 1.0 - behind as f32 / Self::DOTS as f32;
+            dot.color = Color(r, g, b, (a as f32 * fade.max(0.15)) as u8);
+        });
+    }
+}
+
+impl OnEvent for Spinner {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            self.spin(ctx);
+        }
+        true
+    }
+}
+
+/// Places dots evenly around a circle of the given size, clockwise from the top.
+#[derive(Debug)]
+struct RingLayout(f32);
+
+impl Layout for RingLayout {
+    fn request_size(&self, _ctx: &mut Context, _children: Vec<SizeRequest>) -> SizeRequest {
+        SizeRequest::new(self.0, self.0, self.0, self.0)
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let count = children.len().max(1) as f32;
+        let center = (size.0 / 2.0, size.1 / 2.0);
+        children.into_iter().enumerate().map(|(i, child)| {
+            let dot = (child.min_width(), child.min_height());
+            let radius = (size.0.min(size.1) - dot.0) / 2.0;
+            let angle = i as f32 / count * std::f32::consts::TAU;
+            let offset = (center.0 + radius * angle.sin() - dot.0 / 2.0, center.1 - radius * angle.cos() - dot.1 / 2.0);
+            Area{offset, size: dot}
+        }).collect()
+    }
+}
+
+/// Progress from `0.0` to `1.0`, easing towards the latest value.
+#[derive(Debug)]
+struct Progress {
+    target: f32,
+    from: f32,
+    start: Option<Instant>,
+}
+
+impl Progress {
+    const ANIMATION: f32 = 0.3;
+
+    fn new(progress: f32) -> Self {
+        Progress{target: progress.clamp(0.0, 1.0), from: 0.0, start: None}
+    }
+
+    fn set(&mut self, progress: f32) {
+        self.from = self.shown();
+        self.target = progress.clamp(0.0, 1.0);
+        self.start = Some(Instant::now());
+    }
+
+    /// Returns the progress as currently shown, part way through the animation.
+    fn shown(&mut self) -> f32 {
+        let duration = if crate::config::reduce_motion() {0.0} else {Self::ANIMATION};
+        let t = self.start.map(|start| start.elapsed().as_secs_f32() / duration).unwrap_or(1.0);
+        if t >= 1.0 || t.is_nan() {
+            self.start = None;
+            return self.target;
+        }
+        let eased = 1.0 - (1.0 - t).powi(3);
+        self.from + (self.target - self.from) * eased
+    }
+}
@@ -0,0 +1,118 @@
+use pelican_ui::{Align, Area, Component, Context, Drawable, Event, Layout, OnEvent, SizeRequest};
+
+use crate::elements::{Rectangle, Text, TextStyle};
+use crate::events::StickyEvent;
+use crate::layout::{Bin, Column, Offset, Padding, ScrollPosition, Size, Stack};
+use crate::components::ListItemGroup;
+
+/// ## Sectioned List
+///
+/// A vertical list of [`Section`]s. While a section is scrolled inside a [`Content`](crate::Content),
+/// its header stays pinned to the top of the viewport until the next section pushes it away.
+///
+/// ### Example
+/// ```rust
+/// let list = SectionedList::new(vec![
+///     Section::new(ctx, "A", ListItemGroup::new(contacts_a)),
+///     Section::new(ctx, "B", ListItemGroup::new(contacts_b)),
+/// ]);
+/// let content = Content::new(ctx, Offset::Start, vec![Box::new(list)]);
+/// ```
+#[derive(Debug, Component)]
+pub struct SectionedList(Column, Vec<Section>);
+impl OnEvent for SectionedList {}
+
+impl SectionedList {
+    pub fn new(sections: Vec<Section>) -> Self {
+        SectionedList(Column::new(0.0, Offset::Start, Size::fill(), Padding::default()), sections)
+    }
+
+    /// Returns the sections in the list.
+    pub fn sections(&mut self) -> &mut Vec<Section> {&mut self.1}
+}
+
+/// ## Section
+///
+/// A body with a header that sticks to the top of the scrolling [`Content`](crate::Content).
+#[derive(Debug, Component)]
+pub struct Section(SectionLayout, Box<dyn Drawable>, Box<dyn Drawable>);
+
+impl Section {
+    /// Creates a section from a title and a [`ListItemGroup`].
+    pub fn new(ctx: &mut Context, title: &str, group: ListItemGroup) -> Self {
+        Section::custom(Box::new(SectionHeader::new(ctx, title)), Box::new(group))
+    }
+
+    /// Creates a section from any header and body. The header should have an opaque background.
+    pub fn custom(header: Box<dyn Drawable>, body: Box<dyn Drawable>) -> Self {
+        Section(SectionLayout(8.0, None), body, header)
+    }
+
+    /// Returns the body of the section.
+    pub fn body(&mut self) -> &mut Box<dyn Drawable> {&mut self.1}
+    /// Returns the header of the section.
+    pub fn header(&mut self) -> &mut Box<dyn Drawable> {&mut self.2}
+    /// Returns the body as a [`ListItemGroup`] if it is one.
+    pub fn group(&mut self) -> Option<&mut ListItemGroup> {self.1.as_any_mut().downcast_mut::<ListItemGroup>()}
+}
+
+impl OnEvent for Section {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(StickyEvent(Some(position), offset)) = event.downcast_ref::<StickyEvent>() {
+            self.0.1 = Some((position.clone(), *offset));
+        }
+        true
+    }
+}
+
+/// Places the header above the body, pinning it to the top of the viewport while the section is scrolled.
+#[derive(Debug)]
+struct SectionLayout(f32, Option<(ScrollPosition, f32)>);
+
+impl Layout for SectionLayout {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let (widths, heights): (Vec<_>, Vec<_>) = children.into_iter().map(|r|
+            ((r.min_width(), r.max_width()), (r.min_height(), r.max_height()))
+        ).unzip();
+        let width = Size::max(widths);
+        let height = Size::add(heights);
+        SizeRequest::new(width.0, height.0, width.1, height.1).add_height(self.0)
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let header = children[1].get((size.0, children[1].min_height()));
+        let body = children[0].get((size.0, (size.1 - header.1 - self.0).max(0.0)));
+        let pin = self.1.as_ref().map(|(position, top)| position.offset() - top).unwrap_or(0.0);
+        let pin = pin.min(size.1 - header.1).max(0.0);
+
+        vec![
+            Area{offset: (0.0, header.1 + self.0), size: body},
+            Area{offset: (0.0, pin), size: header},
+        ]
+    }
+}
+
+/// ## Section Header
+///
+/// The default header of a [`Section`], a title on the background color.
+#[derive(Debug, Component)]
+pub struct SectionHeader(Stack, Rectangle, Bin<Stack, Text>);
+impl OnEvent for SectionHeader {}
+
+impl SectionHeader {
+    pub fn new(ctx: &mut Context, title: &str) -> Self {
+        let background = ctx.theme.colors.background.primary;
+        let font_size = ctx.theme.fonts.size.h5;
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[1].0, f32::MAX));
+        let height = Size::custom(move |heights: Vec<(f32, f32)>|heights[1]);
+        let padding = Padding(0.0, 8.0, 0.0, 8.0);
+        SectionHeader(
+            Stack(Offset::Start, Offset::Start, width, height, Padding::default()),
+            Rectangle::new(background, 0.0),
+            Bin(Stack(Offset::Start, Offset::Center, Size::Fit, Size::Fit, padding), Text::new(ctx, title, TextStyle::Heading, font_size, Align::Left)),
+        )
+    }
+
+    /// Returns the title text.
+    pub fn title(&mut self) -> &mut Text {self.2.inner()}
+}
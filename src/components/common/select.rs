@@ -0,0 +1,355 @@
+use pelican_ui::{
+    Area, Component, Context,
+    Drawable, Event, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+    KeyboardEvent, KeyboardState, Key, NamedKey,
+};
+
+use crate::events::{AnchorEvent, DismissPopoverEvent, PresentPopoverEvent};
+use crate::components::{Button, ButtonSize, ButtonState, ButtonStyle, ButtonWidth, Popover, TextInput};
+use crate::layout::{Column, Offset, Opt, Padding, Scroll, ScrollAnchor, Size, Stack};
+use crate::utils::{Callback, ElementID};
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Instant;
+
+/// ## Select
+///
+/// A button that opens a dropdown of options under it, for picking one value from a long list.
+///
+/// The dropdown scrolls when it is taller than the space available. On desktop the options
+/// can be highlighted with the arrow keys and picked with Enter. A search field that filters
+/// the options can be added with [`Select::searchable`].
+///
+/// ### Example
+/// ```rust
+/// let select = Select::new(ctx, "Currency", vec!["USD", "EUR", "GBP", "JPY"], Some(0), |ctx: &mut Context, index: usize| {
+///     println!("Picked currency {index}");
+/// }).searchable();
+/// ```
+#[derive(Component)]
+pub struct Select(
+    Stack,
+    Button,
+    #[skip] ElementID,
+    #[skip] Vec<String>,
+    #[skip] Option<usize>,
+    #[skip] Box<dyn FnMut(&mut Context, usize)>,
+    #[skip] Receiver<()>,
+    #[skip] MenuChannel,
+    #[skip] bool,
+    #[skip] String,
+);
+
+impl Select {
+    pub fn new(
+        ctx: &mut Context,
+        placeholder: &str,
+        options: Vec<&str>,
+        selected: Option<usize>,
+        on_change: impl FnMut(&mut Context, usize) + 'static,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let label = selected.and_then(|i| options.get(i).copied()).unwrap_or(placeholder);
+        let trigger = Button::new(
+            ctx, None, None, Some(label), Some(("down", None)), ButtonSize::Medium, ButtonWidth::Expand,
+            ButtonStyle::Secondary, ButtonState::Default, Offset::Start, move |_ctx: &mut Context| {let _ = sender.send(());}, None
+        );
+
+        Select(
+            Stack(Offset::Start, Offset::Start, Size::fill(), Size::Fit, Padding::default()),
+            trigger,
+            ElementID::new(),
+            options.into_iter().map(|o| o.to_string()).collect(),
+            selected,
+            Box::new(on_change),
+            receiver,
+            MenuChannel::new(),
+            false,
+            placeholder.to_string()
+        )
+    }
+
+    /// Adds a search field to the dropdown that filters the options.
+    pub fn searchable(mut self) -> Self {
+        self.8 = true;
+        self
+    }
+
+    /// Returns the index of the selected option.
+    pub fn selected(&self) -> Option<usize> { self.4 }
+    /// Returns the options.
+    pub fn options(&self) -> &[String] { &self.3 }
+    /// Returns the id the dropdown is anchored to.
+    pub fn id(&self) -> ElementID { self.2 }
+
+    /// Selects the option at `index` without calling `on_change`, or shows the placeholder again for `None`.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.4 = index.filter(|i| *i < self.3.len());
+        let label = self.4.map(|i| self.3[i].clone()).unwrap_or_else(|| self.9.clone());
+        if let Some(text) = self.1.label().as_mut() { text.text().spans[0].text = label; }
+    }
+
+    fn open(&mut self, ctx: &mut Context) {
+        let options = self.3.iter().map(|o| (o.as_str(), None)).collect();
+        let menu = MenuList::new(ctx, options, self.4, self.8, self.7.sender());
+        let popover = Popover::below(ctx, self.2, Box::new(menu)).match_width();
+        ctx.trigger_event(PresentPopoverEvent::new(popover));
+    }
+}
+
+impl OnEvent for Select {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(anchor) = event.downcast_ref::<AnchorEvent>() {
+            anchor.report(self.2);
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            if self.6.try_iter().last().is_some() { self.open(ctx); }
+            if let Some(index) = self.7.chosen() {
+                ctx.trigger_event(DismissPopoverEvent);
+                if self.4 != Some(index) {
+                    self.select(Some(index));
+                    (self.5)(ctx, index);
+                }
+            }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for Select {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Select({:?})", self.4)
+    }
+}
+
+/// ## Context Menu
+///
+/// Attaches a menu of actions to any component. The menu opens where the component is long-pressed.
+///
+/// pelican_ui reports every mouse button as the same press, so desktop users long-press as well.
+/// Apps that read secondary clicks themselves can open the menu with [`ContextMenu::open`].
+///
+/// ### Example
+/// ```rust
+/// let text = Text::new(ctx, "bc1qxy2k...", TextStyle::Primary, 16.0, Align::Left);
+/// let menu = ContextMenu::new(text)
+///     .item_with_icon("copy", "Copy", |_ctx: &mut Context| println!("Copied"))
+///     .item("Delete", |ctx: &mut Context| ctx.trigger_event(NavigationEvent::Pop));
+/// ```
+#[derive(Component)]
+pub struct ContextMenu(Stack, Box<dyn Drawable>, #[skip] ElementID, #[skip] Vec<(String, Option<&'static str>, Callback)>, #[skip] MenuChannel, #[skip] Option<(Instant, (f32, f32))>);
+
+impl ContextMenu {
+    pub const LONG_PRESS: f32 = 0.5;
+
+    pub fn new(content: impl Drawable + 'static) -> Self {
+        ContextMenu(Stack::default(), Box::new(content), ElementID::new(), Vec::new(), MenuChannel::new(), None)
+    }
+
+    /// Adds an action to the menu.
+    pub fn item(mut self, label: &str, on_select: impl FnMut(&mut Context) + 'static) -> Self {
+        self.3.push((label.to_string(), None, Box::new(on_select)));
+        self
+    }
+
+    /// Adds an action with an icon to the menu.
+    pub fn item_with_icon(mut self, icon: &'static str, label: &str, on_select: impl FnMut(&mut Context) + 'static) -> Self {
+        self.3.push((label.to_string(), Some(icon), Box::new(on_select)));
+        self
+    }
+
+    /// Returns the component the menu is attached to.
+    pub fn content(&mut self) -> &mut Box<dyn Drawable> { &mut self.1 }
+
+    /// Opens the menu at `point`, relative to the component.
+    pub fn open(&mut self, ctx: &mut Context, point: (f32, f32)) {
+        let items = self.3.iter().map(|(label, icon, _)| (label.as_str(), *icon)).collect();
+        let menu = MenuList::new(ctx, items, None, false, self.4.sender());
+        let popover = Popover::at(ctx, self.2, point, Box::new(menu));
+        ctx.trigger_event(PresentPopoverEvent::new(popover));
+    }
+}
+
+impl OnEvent for ContextMenu {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(anchor) = event.downcast_ref::<AnchorEvent>() {
+            anchor.report(self.2);
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            match (event.state, event.position) {
+                (MouseState::Pressed, Some(position)) => self.5 = Some((Instant::now(), position)),
+                (MouseState::Moved, Some(position)) => {
+                    let moved = self.5.map(|(_, start)| (position.0 - start.0).abs() > 8.0 || (position.1 - start.1).abs() > 8.0);
+                    if moved.unwrap_or(false) { self.5 = None; }
+                },
+                (MouseState::Pressed | MouseState::Moved, None) | (MouseState::Released, _) | (MouseState::Scroll(..), _) => self.5 = None,
+                _ => {}
+            }
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            if let Some((start, point)) = self.5 {
+                if start.elapsed().as_secs_f32() >= Self::LONG_PRESS {
+                    self.5 = None;
+                    ctx.hardware.haptic();
+                    self.open(ctx, point);
+                }
+            }
+            if let Some(index) = self.4.chosen() {
+                ctx.trigger_event(DismissPopoverEvent);
+                if let Some((_, _, on_select)) = self.3.get_mut(index) { on_select(ctx); }
+            }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for ContextMenu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ContextMenu({:?})", self.1)
+    }
+}
+
+/// Carries the index of the option picked in an open menu back to the component that opened it.
+struct MenuChannel(Sender<usize>, Receiver<usize>);
+
+impl MenuChannel {
+    fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        MenuChannel(sender, receiver)
+    }
+
+    fn sender(&self) -> Sender<usize> { self.0.clone() }
+    fn chosen(&self) -> Option<usize> { self.1.try_iter().last() }
+}
+
+/// The content of a [`Select`] or [`ContextMenu`] popover: an optional search field above the options.
+#[derive(Component)]
+struct MenuList(Column, Option<TextInput>, MenuRows, #[skip] Vec<String>, #[skip] Option<usize>, #[skip] Option<usize>, #[skip] String, #[skip] Sender<usize>);
+
+impl MenuList {
+    fn new(ctx: &mut Context, options: Vec<(&str, Option<&'static str>)>, selected: Option<usize>, searchable: bool, sender: Sender<usize>) -> Self {
+        let rows = options.iter().enumerate().map(|(i, (label, icon))| {
+            let sender = sender.clone();
+            let state = if selected == Some(i) {ButtonState::Selected} else {ButtonState::Default};
+            let button = Button::new(
+                ctx, None, icon.map(|i| (i, None)), Some(label), None, ButtonSize::Medium, ButtonWidth::Expand,
+                ButtonStyle::Ghost, state, Offset::Start, move |_ctx: &mut Context| {let _ = sender.send(i);}, None
+            );
+            Opt::new(button, true)
+        }).collect();
+
+        MenuList(
+            Column::new(4.0, Offset::Start, Size::Fit, Padding::default()),
+            searchable.then(|| TextInput::new(ctx, None, None, "Search", None, TextInput::NO_ICON, false)),
+            MenuRows::new(rows),
+            options.into_iter().map(|(label, _)| label.to_string()).collect(),
+            selected,
+            None,
+            String::new(),
+            sender
+        )
+    }
+
+    /// Returns the indexes of the options matching the search.
+    fn visible(&self) -> Vec<usize> {
+        (0..self.3.len()).filter(|i| self.2.1[*i].is_showing()).collect()
+    }
+
+    fn filter(&mut self, ctx: &mut Context, query: String) {
+        let search = query.to_lowercase();
+        self.3.iter().zip(self.2.1.iter_mut()).for_each(|(label, row)| {
+            row.display(label.to_lowercase().contains(&search));
+        });
+        self.6 = query;
+        self.2.0.set_scroll(0.0);
+        self.highlight(ctx, self.visible().first().copied());
+    }
+
+    fn highlight(&mut self, ctx: &mut Context, index: Option<usize>) {
+        let rows = &mut self.2.1;
+        if let Some(row) = self.5.and_then(|i| rows.get_mut(i)) {
+            *row.inner().status() = if self.5 == self.4 {ButtonState::Selected} else {ButtonState::Default};
+            row.inner().color(ctx);
+        }
+        if let Some(row) = index.and_then(|i| rows.get_mut(i)) {
+            *row.inner().status() = ButtonState::Hover;
+            row.inner().color(ctx);
+        }
+        self.5 = index;
+
+        let visible = self.visible();
+        if let Some(position) = index.and_then(|i| visible.iter().position(|v| *v == i)) {
+            let height = ButtonSize::Medium.sizes().0;
+            let offset = self.2.0.scroll_offset();
+            self.2.0.scroll_into_view((position as f32 * height) - offset, height, Offset::Center, false);
+        }
+    }
+
+    /// Moves the highlight `step` options up or down through the visible options.
+    fn step(&mut self, ctx: &mut Context, step: isize) {
+        let visible = self.visible();
+        if visible.is_empty() { return; }
+        let current = self.5.or(self.4).and_then(|i| visible.iter().position(|v| *v == i));
+        let next = match current {
+            Some(position) => (position as isize + step).clamp(0, visible.len() as isize - 1) as usize,
+            None if step > 0 => 0,
+            None => visible.len() - 1,
+        };
+        self.highlight(ctx, Some(visible[next]));
+    }
+}
+
+impl OnEvent for MenuList {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            let query = self.1.as_mut().map(|input| input.value().clone());
+            if let Some(query) = query.filter(|q| *q != self.6) { self.filter(ctx, query); }
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(key)}) = event.downcast_ref::<KeyboardEvent>() {
+            if crate::config::IS_MOBILE { return true; }
+            match key {
+                NamedKey::ArrowDown => self.step(ctx, 1),
+                NamedKey::ArrowUp => self.step(ctx, -1),
+                NamedKey::Enter => if let Some(index) = self.5 { let _ = self.7.send(index); },
+                _ => {}
+            }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for MenuList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MenuList({})", self.3.len())
+    }
+}
+
+/// The options of a [`MenuList`], scrolling once they are taller than [`MenuRows::MAX_HEIGHT`].
+#[derive(Debug, Component)]
+struct MenuRows(Scroll, Vec<Opt<Button>>);
+
+impl MenuRows {
+    const MAX_HEIGHT: f32 = 280.0;
+
+    fn new(rows: Vec<Opt<Button>>) -> Self {
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths.iter().map(|w| w.0).fold(0.0, f32::max), f32::MAX));
+        let height = Size::custom(move |heights: Vec<(f32, f32)>|{
+            let total = heights.iter().map(|h| h.0).sum::<f32>();
+            (total.min(Self::MAX_HEIGHT), total.min(Self::MAX_HEIGHT))
+        });
+        MenuRows(Scroll::new(Offset::Start, Offset::Start, width, height, Padding::default(), ScrollAnchor::Start), rows)
+    }
+}
+
+impl OnEvent for MenuRows {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(MouseEvent { state: MouseState::Scroll(_, y), position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.adjust_scroll(*y);
+        } else if let Some(MouseEvent { state: MouseState::Pressed, position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.hold();
+        } else if let Some(MouseEvent { state: MouseState::Released, .. }) = event.downcast_ref::<MouseEvent>() {
+            self.0.release();
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            self.0.tick();
+        }
+        true
+    }
+}
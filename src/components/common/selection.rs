@@ -0,0 +1,355 @@
+use pelican_ui::{
+    Align, Area, Color, Component, Context,
+    Drawable, Event, Image, Layout,
+    MouseEvent, MouseState, OnEvent,
+    Shape, ShapeType, SizeRequest, TickEvent,
+};
+
+use crate::elements::{Icon, OutlinedRectangle, RoundedRectangle, Text, TextStyle};
+use crate::components::{ButtonState, ButtonStyle};
+use crate::layout::{Bin, Column, Offset, Padding, Row, Size, Stack};
+
+use std::sync::{Arc, Mutex, mpsc::{self, Receiver, Sender}};
+use std::time::Instant;
+
+/// Updates `state` from `event` and returns whether the state changed and whether a press on the control was released.
+fn clicked(state: &mut ButtonState, ctx: &mut Context, event: MouseEvent) -> (bool, bool) {
+    let pressed = *state == ButtonState::Pressed;
+    let changed = state.handle(ctx, event).is_some();
+    (changed, pressed && matches!(event, MouseEvent{state: MouseState::Released, position: Some(_)}))
+}
+
+/// ## Toggle
+///
+/// An on/off switch. The knob slides across the track when the toggle is pressed.
+///
+/// Toggles can be placed at the end of a [`ListItem`](crate::ListItem) with [`ListItem::trailing`](crate::ListItem::trailing).
+///
+/// ### Example
+/// ```rust
+/// let toggle = Toggle::new(ctx, true, |ctx: &mut Context, on: bool| println!("Notifications: {on}"));
+/// ```
+#[derive(Component)]
+pub struct Toggle(ToggleLayout, OutlinedRectangle, Shape, #[skip] bool, #[skip] ButtonState, #[skip] Box<dyn FnMut(&mut Context, bool)>, #[skip] Option<Instant>);
+
+impl Toggle {
+    pub const DURATION: f32 = 0.15;
+
+    pub fn new(ctx: &mut Context, on: bool, on_change: impl FnMut(&mut Context, bool) + 'static) -> Self {
+        let mut toggle = Toggle(
+            ToggleLayout(Arc::new(Mutex::new(if on {1.0} else {0.0}))),
+            OutlinedRectangle::new(Color(0, 0, 0, 0), Color(0, 0, 0, 0), 14.0, 1.0),
+            Shape{shape: ShapeType::Ellipse(0.0, (22.0, 22.0), 0.0), color: Color(0, 0, 0, 0)},
+            on,
+            ButtonState::Default,
+            Box::new(on_change),
+            None
+        );
+        toggle.color(ctx);
+        toggle
+    }
+
+    /// Returns `true` if the toggle is on.
+    pub fn is_on(&self) -> bool { self.3 }
+
+    /// Turns the toggle on or off without calling `on_change`.
+    pub fn set(&mut self, ctx: &mut Context, on: bool) {
+        if self.3 != on { self.6 = Some(Instant::now()); }
+        self.3 = on;
+        self.color(ctx);
+    }
+
+    /// Disables or enables the toggle.
+    pub fn set_disabled(&mut self, ctx: &mut Context, disabled: bool) {
+        self.4 = if disabled {ButtonState::Disabled} else {ButtonState::Default};
+        self.color(ctx);
+    }
+
+    fn color(&mut self, ctx: &mut Context) {
+        let colors = match (self.4, self.3) {
+            (ButtonState::Disabled, on) => ButtonState::Disabled.color(ctx, if on {ButtonStyle::Primary} else {ButtonStyle::Secondary}),
+            (_, true) => ButtonState::Selected.color(ctx, ButtonStyle::Primary),
+            (state, false) => state.color(ctx, ButtonStyle::Secondary),
+        };
+        *self.1.background() = colors.background;
+        *self.1.outline() = colors.outline;
+        self.2.color = colors.label;
+    }
+}
+
+impl OnEvent for Toggle {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            if let Some(start) = self.6 {
+                let duration = if crate::config::reduce_motion() {0.0} else {Self::DURATION};
+                let t = if duration > 0.0 {(start.elapsed().as_secs_f32() / duration).min(1.0)} else {1.0};
+                *self.0.0.lock().unwrap() = if self.3 {t} else {1.0 - t};
+                if t >= 1.0 { self.6 = None; }
+            }
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            if self.4 == ButtonState::Disabled { return false; }
+            let (changed, clicked) = clicked(&mut self.4, ctx, *event);
+            if changed { self.color(ctx); }
+            if clicked {
+                ctx.hardware.haptic();
+                self.set(ctx, !self.3);
+                (self.5)(ctx, self.3);
+            }
+        }
+        false
+    }
+}
+
+impl std::fmt::Debug for Toggle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Toggle({})", self.3)
+    }
+}
+
+/// Places the knob of a [`Toggle`] along its track as its progress goes from off (0) to on (1).
+#[derive(Debug)]
+struct ToggleLayout(Arc<Mutex<f32>>);
+
+impl ToggleLayout {
+    const SIZE: (f32, f32) = (48.0, 28.0);
+    const INSET: f32 = 3.0;
+}
+
+impl Layout for ToggleLayout {
+    fn request_size(&self, _ctx: &mut Context, _children: Vec<SizeRequest>) -> SizeRequest {
+        SizeRequest::new(Self::SIZE.0, Self::SIZE.1, Self::SIZE.0, Self::SIZE.1)
+    }
+
+    fn build(&self, _ctx: &mut Context, _size: (f32, f32), _children: Vec<SizeRequest>) -> Vec<Area> {
+        let p = *self.0.lock().unwrap();
+        let p = 1.0 - (1.0 - p).powi(3);
+        let knob = Self::SIZE.1 - (Self::INSET * 2.0);
+        let travel = Self::SIZE.0 - Self::SIZE.1;
+        vec![
+            Area{offset: (0.0, 0.0), size: Self::SIZE},
+            Area{offset: (Self::INSET + (travel * p), Self::INSET), size: (knob, knob)},
+        ]
+    }
+}
+
+/// The state of a [`Checkbox`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Neither checked nor unchecked, like a "select all" box when only some items are selected.
+    Indeterminate,
+}
+
+/// ## Checkbox
+///
+/// A box that can be checked, unchecked, or shown as indeterminate.
+/// Pressing an indeterminate checkbox checks it.
+///
+/// Checkboxes can be placed at the end of a [`ListItem`](crate::ListItem) with [`ListItem::trailing`](crate::ListItem::trailing).
+///
+/// ### Example
+/// ```rust
+/// let checkbox = Checkbox::new(ctx, CheckState::Indeterminate, |ctx: &mut Context, state: CheckState| println!("{state:?}"));
+/// ```
+#[derive(Component)]
+pub struct Checkbox(Stack, OutlinedRectangle, Option<Image>, Option<Bin<Stack, RoundedRectangle>>, #[skip] CheckState, #[skip] ButtonState, #[skip] Box<dyn FnMut(&mut Context, CheckState)>);
+
+impl Checkbox {
+    pub fn new(ctx: &mut Context, state: CheckState, on_change: impl FnMut(&mut Context, CheckState) + 'static) -> Self {
+        let mut checkbox = Checkbox(
+            Stack(Offset::Center, Offset::Center, Size::Static(20.0), Size::Static(20.0), Padding::default()),
+            OutlinedRectangle::new(Color(0, 0, 0, 0), Color(0, 0, 0, 0), 4.0, 1.0),
+            None,
+            None,
+            state,
+            ButtonState::Default,
+            Box::new(on_change)
+        );
+        checkbox.color(ctx);
+        checkbox
+    }
+
+    /// Returns the state of the checkbox.
+    pub fn state(&self) -> CheckState { self.4 }
+
+    /// Returns `true` if the checkbox is checked.
+    pub fn is_checked(&self) -> bool { self.4 == CheckState::Checked }
+
+    /// Sets the state of the checkbox without calling `on_change`.
+    pub fn set(&mut self, ctx: &mut Context, state: CheckState) {
+        self.4 = state;
+        self.color(ctx);
+    }
+
+    /// Disables or enables the checkbox.
+    pub fn set_disabled(&mut self, ctx: &mut Context, disabled: bool) {
+        self.5 = if disabled {ButtonState::Disabled} else {ButtonState::Default};
+        self.color(ctx);
+    }
+
+    fn color(&mut self, ctx: &mut Context) {
+        let marked = self.4 != CheckState::Unchecked;
+        let colors = match (self.5, marked) {
+            (ButtonState::Disabled, marked) => ButtonState::Disabled.color(ctx, if marked {ButtonStyle::Primary} else {ButtonStyle::Secondary}),
+            (_, true) => ButtonState::Selected.color(ctx, ButtonStyle::Primary),
+            (state, false) => state.color(ctx, ButtonStyle::Secondary),
+        };
+        *self.1.background() = colors.background;
+        *self.1.outline() = colors.outline;
+        self.2 = (self.4 == CheckState::Checked).then(|| Icon::new(ctx, "checkmark", colors.label, 16.0));
+        self.3 = (self.4 == CheckState::Indeterminate).then(|| Bin(
+            Stack(Offset::Center, Offset::Center, Size::Static(10.0), Size::Static(2.0), Padding::default()),
+            RoundedRectangle::new(0.0, 1.0, colors.label)
+        ));
+    }
+}
+
+impl OnEvent for Checkbox {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            if self.5 == ButtonState::Disabled { return false; }
+            let (changed, clicked) = clicked(&mut self.5, ctx, *event);
+            if changed { self.color(ctx); }
+            if clicked {
+                ctx.hardware.haptic();
+                let state = if self.4 == CheckState::Checked {CheckState::Unchecked} else {CheckState::Checked};
+                self.set(ctx, state);
+                (self.6)(ctx, state);
+            }
+        }
+        false
+    }
+}
+
+impl std::fmt::Debug for Checkbox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Checkbox({:?})", self.4)
+    }
+}
+
+/// ## Radio Group
+///
+/// A vertical list of labeled radio buttons where only one option can be selected.
+///
+/// Unlike [`ListItemSelector`](crate::ListItemSelector), the selection is kept inside the group,
+/// so several groups can be used on the same page.
+///
+/// ### Example
+/// ```rust
+/// let group = RadioGroup::new(ctx, vec!["Light", "Dark", "System"], Some(2), |ctx: &mut Context, index: usize| println!("Theme {index}"));
+/// ```
+#[derive(Component)]
+pub struct RadioGroup(Column, Vec<RadioOption>, #[skip] Option<usize>, #[skip] Box<dyn FnMut(&mut Context, usize)>, #[skip] Receiver<usize>);
+
+impl RadioGroup {
+    pub fn new(ctx: &mut Context, options: Vec<&str>, selected: Option<usize>, on_change: impl FnMut(&mut Context, usize) + 'static) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let options = options.into_iter().enumerate().map(|(i, label)| {
+            RadioOption::new(ctx, label, selected == Some(i), i, sender.clone())
+        }).collect();
+        RadioGroup(Column::new(4.0, Offset::Start, Size::Fit, Padding::default()), options, selected, Box::new(on_change), receiver)
+    }
+
+    /// Returns the index of the selected option.
+    pub fn selected(&self) -> Option<usize> { self.2 }
+
+    /// Selects the option at `index` without calling `on_change`.
+    pub fn select(&mut self, ctx: &mut Context, index: Option<usize>) {
+        self.2 = index;
+        self.1.iter_mut().enumerate().for_each(|(i, option)| option.set(ctx, index == Some(i)));
+    }
+
+    /// Disables or enables every option.
+    pub fn set_disabled(&mut self, ctx: &mut Context, disabled: bool) {
+        self.1.iter_mut().for_each(|option| option.set_disabled(ctx, disabled));
+    }
+
+    /// Disables or enables the option at `index`.
+    pub fn set_option_disabled(&mut self, ctx: &mut Context, index: usize, disabled: bool) {
+        if let Some(option) = self.1.get_mut(index) { option.set_disabled(ctx, disabled); }
+    }
+}
+
+impl OnEvent for RadioGroup {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            if let Some(index) = self.4.try_iter().last() {
+                if self.2 != Some(index) {
+                    self.select(ctx, Some(index));
+                    (self.3)(ctx, index);
+                }
+            }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for RadioGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RadioGroup({:?})", self.2)
+    }
+}
+
+#[derive(Component)]
+struct RadioOption(Row, Image, Text, #[skip] bool, #[skip] ButtonState, #[skip] usize, #[skip] Sender<usize>);
+
+impl RadioOption {
+    fn new(ctx: &mut Context, label: &str, selected: bool, index: usize, sender: Sender<usize>) -> Self {
+        let font_size = ctx.theme.fonts.size.md;
+        let mut option = RadioOption(
+            Row::new(8.0, Offset::Center, Size::Fit, Padding(0.0, 4.0, 0.0, 4.0)),
+            Icon::new(ctx, "radio", Color(0, 0, 0, 0), 24.0),
+            Text::new(ctx, label, TextStyle::Primary, font_size, Align::Left),
+            selected,
+            ButtonState::Default,
+            index,
+            sender
+        );
+        option.color(ctx);
+        option
+    }
+
+    fn set(&mut self, ctx: &mut Context, selected: bool) {
+        self.3 = selected;
+        self.color(ctx);
+    }
+
+    fn set_disabled(&mut self, ctx: &mut Context, disabled: bool) {
+        self.4 = if disabled {ButtonState::Disabled} else {ButtonState::Default};
+        self.color(ctx);
+    }
+
+    fn color(&mut self, ctx: &mut Context) {
+        let label = self.4.color(ctx, ButtonStyle::Ghost).label;
+        let icon = match (self.4, self.3) {
+            (ButtonState::Disabled, _) => label,
+            (_, true) => ButtonState::Selected.color(ctx, ButtonStyle::Primary).background,
+            (_, false) => label,
+        };
+        self.1 = Icon::new(ctx, if self.3 {"radio_filled"} else {"radio"}, icon, 24.0);
+        self.2.text().spans[0].color = label;
+    }
+}
+
+impl OnEvent for RadioOption {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            if self.4 == ButtonState::Disabled { return false; }
+            let (changed, clicked) = clicked(&mut self.4, ctx, *event);
+            if changed { self.color(ctx); }
+            if clicked {
+                ctx.hardware.haptic();
+                let _ = self.6.send(self.5);
+            }
+        }
+        false
+    }
+}
+
+impl std::fmt::Debug for RadioOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RadioOption({})", self.5)
+    }
+}
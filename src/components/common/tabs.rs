@@ -0,0 +1,374 @@
+use pelican_ui::{
+    Area, Color, Component, Context,
+    Drawable, Event, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+};
+
+use crate::events::TabSelect;
+use crate::elements::{OutlinedRectangle, RoundedRectangle};
+use crate::components::{Button, ButtonSize, ButtonState, ButtonStyle, ButtonWidth};
+use crate::layout::{Column, Offset, OneOf, Padding, Scroll, ScrollAnchor, Size};
+use crate::utils::ElementID;
+
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// ## Segmented Control
+///
+/// A row of equally sized segments where exactly one is selected, like "Send / Receive" or "Day / Week / Month".
+/// The selection indicator slides to the pressed segment.
+///
+/// Segments are selected with a [`TabSelect`] carrying their [`ElementID`], so several controls can share a page.
+///
+/// ### Example
+/// ```rust
+/// let control = SegmentedControl::new(ctx, vec!["Day", "Week", "Month"], 0, |ctx: &mut Context, index: usize| println!("Range {index}"));
+/// ```
+#[derive(Component)]
+pub struct SegmentedControl(SegmentLayout, OutlinedRectangle, RoundedRectangle, Vec<Button>, #[skip] Tabs);
+
+impl SegmentedControl {
+    pub fn new(ctx: &mut Context, labels: Vec<&str>, selected: usize, on_change: impl FnMut(&mut Context, usize) + 'static) -> Self {
+        let colors = &ctx.theme.colors;
+        let (background, outline) = (colors.background.secondary, colors.outline.secondary);
+        let indicator = ButtonState::Selected.color(ctx, ButtonStyle::Ghost).background;
+        let (tabs, buttons) = Tabs::new(ctx, labels, selected, ButtonWidth::Expand, Box::new(on_change));
+        SegmentedControl(
+            SegmentLayout(tabs.3.clone()),
+            OutlinedRectangle::new(background, outline, SegmentLayout::HEIGHT / 2.0, 1.0),
+            RoundedRectangle::new(0.0, (SegmentLayout::HEIGHT / 2.0) - SegmentLayout::INSET, indicator),
+            buttons,
+            tabs
+        )
+    }
+
+    /// Returns the index of the selected segment.
+    pub fn selected(&self) -> usize { self.4.1 }
+
+    /// Selects the segment at `index` without calling `on_change`.
+    pub fn select(&mut self, ctx: &mut Context, index: usize) {
+        self.4.select(ctx, &mut self.3, index);
+    }
+}
+
+impl OnEvent for SegmentedControl {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TabSelect(id)) = event.downcast_ref::<TabSelect>() {
+            self.4.pressed(ctx, &mut self.3, *id);
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            self.4.tick(ctx, &mut self.3);
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for SegmentedControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SegmentedControl({})", self.4.1)
+    }
+}
+
+/// Places the track, the indicator and equally sized segments of a [`SegmentedControl`].
+#[derive(Debug)]
+struct SegmentLayout(Arc<Mutex<Indicator>>);
+
+impl SegmentLayout {
+    const HEIGHT: f32 = 40.0;
+    const INSET: f32 = 4.0;
+}
+
+impl Layout for SegmentLayout {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let segments = children.iter().skip(2).map(|s| s.min_width()).collect::<Vec<_>>();
+        let widest = segments.iter().copied().fold(0.0, f32::max);
+        let width = (widest * segments.len() as f32) + (Self::INSET * 2.0);
+        SizeRequest::new(width, Self::HEIGHT, f32::MAX, Self::HEIGHT)
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let count = children.len().saturating_sub(2).max(1) as f32;
+        let width = (size.0 - (Self::INSET * 2.0)) / count;
+        let height = Self::HEIGHT - (Self::INSET * 2.0);
+
+        let mut indicator = self.0.lock().unwrap();
+        indicator.rects = (0..children.len().saturating_sub(2)).map(|i| (Self::INSET + (width * i as f32), width)).collect();
+        let (x, w) = indicator.rect();
+
+        let mut areas = vec![
+            Area{offset: (0.0, 0.0), size: (size.0, Self::HEIGHT)},
+            Area{offset: (x, Self::INSET), size: (w, height)},
+        ];
+        areas.extend(indicator.rects.iter().map(|(x, w)| Area{offset: (*x, Self::INSET), size: (*w, height)}));
+        areas
+    }
+}
+
+/// ## Tab Bar
+///
+/// A horizontally scrollable row of tabs with an underline that slides to the selected tab.
+/// The selected tab is scrolled into view.
+///
+/// Use a [`TabView`] to switch between content panes with the tab bar.
+///
+/// ### Example
+/// ```rust
+/// let tabs = TabBar::new(ctx, vec!["All", "Sent", "Received", "Pending"], 0, |ctx: &mut Context, index: usize| println!("Tab {index}"));
+/// ```
+#[derive(Component)]
+pub struct TabBar(Scroll, TabRow, #[skip] Tabs);
+
+impl TabBar {
+    pub fn new(ctx: &mut Context, labels: Vec<&str>, selected: usize, on_change: impl FnMut(&mut Context, usize) + 'static) -> Self {
+        let color = ctx.theme.colors.brand.primary;
+        let (tabs, buttons) = Tabs::new(ctx, labels, selected, ButtonWidth::Hug, Box::new(on_change));
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(0.0, widths[0].1));
+        TabBar(
+            Scroll::horizontal(Offset::Start, Offset::Start, width, Size::Fit, Padding::default(), ScrollAnchor::Start),
+            TabRow(TabLayout(tabs.3.clone()), RoundedRectangle::new(0.0, 1.0, color), buttons),
+            tabs
+        )
+    }
+
+    /// Returns the index of the selected tab.
+    pub fn selected(&self) -> usize { self.2.1 }
+
+    /// Selects the tab at `index` without calling `on_change`.
+    pub fn select(&mut self, ctx: &mut Context, index: usize) {
+        self.2.select(ctx, &mut self.1.2, index);
+        self.reveal();
+    }
+
+    /// Scrolls the selected tab into view.
+    fn reveal(&mut self) {
+        let rect = self.2.3.lock().unwrap().rects.get(self.2.1).copied();
+        if let Some((x, w)) = rect {
+            let position = x - self.0.scroll_offset();
+            self.0.scroll_into_view(position, w, Offset::Center, !crate::config::reduce_motion());
+        }
+    }
+}
+
+impl OnEvent for TabBar {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TabSelect(id)) = event.downcast_ref::<TabSelect>() {
+            if self.2.pressed(ctx, &mut self.1.2, *id) { self.reveal(); }
+        } else if let Some(MouseEvent { state: MouseState::Scroll(x, y), position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.adjust_scroll(if *x != 0.0 {*x} else {*y});
+        } else if let Some(MouseEvent { state: MouseState::Pressed, position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.hold();
+        } else if let Some(MouseEvent { state: MouseState::Released, .. }) = event.downcast_ref::<MouseEvent>() {
+            self.0.release();
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            self.2.tick(ctx, &mut self.1.2);
+            self.0.tick();
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for TabBar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TabBar({})", self.2.1)
+    }
+}
+
+#[derive(Debug, Component)]
+struct TabRow(TabLayout, RoundedRectangle, Vec<Button>);
+impl OnEvent for TabRow {}
+
+/// Places the tabs of a [`TabBar`] one after another with the underline below the selected tab.
+#[derive(Debug)]
+struct TabLayout(Arc<Mutex<Indicator>>);
+
+impl TabLayout {
+    const SPACING: f32 = 8.0;
+    const PADDING: f32 = 16.0;
+    const UNDERLINE: f32 = 2.0;
+}
+
+impl Layout for TabLayout {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let tabs = &children[1..];
+        let width = tabs.iter().map(|t| t.min_width()).sum::<f32>()
+            + (Self::SPACING * tabs.len().saturating_sub(1) as f32)
+            + (Self::PADDING * 2.0);
+        let height = tabs.iter().map(|t| t.min_height()).fold(0.0, f32::max) + Self::UNDERLINE;
+        SizeRequest::new(width, height, width, height)
+    }
+
+    fn build(&self, _ctx: &mut Context, _size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let tabs = &children[1..];
+        let height = tabs.iter().map(|t| t.min_height()).fold(0.0, f32::max);
+
+        let mut x = Self::PADDING;
+        let mut indicator = self.0.lock().unwrap();
+        indicator.rects = tabs.iter().map(|t| {
+            let rect = (x, t.min_width());
+            x += t.min_width() + Self::SPACING;
+            rect
+        }).collect();
+        let (x, w) = indicator.rect();
+
+        let mut areas = vec![Area{offset: (x, height), size: (w, Self::UNDERLINE)}];
+        areas.extend(indicator.rects.iter().zip(tabs).map(|((x, w), t)| Area{offset: (*x, 0.0), size: (*w, t.min_height())}));
+        areas
+    }
+}
+
+/// ## Tab View
+///
+/// A [`TabBar`] above content panes, showing the pane of the selected tab with a [`OneOf`].
+///
+/// ### Example
+/// ```rust
+/// let view = TabView::new(ctx, vec![
+///     ("Sent", Box::new(sent_list) as Box<dyn Drawable>),
+///     ("Received", Box::new(received_list)),
+/// ], 0, |_ctx: &mut Context, _index: usize| ());
+/// ```
+#[derive(Debug, Component)]
+pub struct TabView(Column, TabBar, OneOf);
+
+impl TabView {
+    pub fn new(ctx: &mut Context, tabs: Vec<(&str, Box<dyn Drawable>)>, selected: usize, on_change: impl FnMut(&mut Context, usize) + 'static) -> Self {
+        let (labels, panes): (Vec<_>, Vec<_>) = tabs.into_iter().unzip();
+        TabView(
+            Column::new(16.0, Offset::Start, Size::Fit, Padding::default()),
+            TabBar::new(ctx, labels, selected, on_change),
+            OneOf::new(panes, selected)
+        )
+    }
+
+    /// Returns the tab bar.
+    pub fn tab_bar(&mut self) -> &mut TabBar { &mut self.1 }
+    /// Returns the content panes.
+    pub fn panes(&mut self) -> &mut OneOf { &mut self.2 }
+
+    /// Selects the tab and pane at `index` without calling `on_change`.
+    pub fn select(&mut self, ctx: &mut Context, index: usize) {
+        self.1.select(ctx, index);
+        self.2.display(index);
+    }
+}
+
+impl OnEvent for TabView {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() && self.1.selected() != self.2.index() {
+            self.2.display(self.1.selected());
+        }
+        true
+    }
+}
+
+/// The selection shared by a [`SegmentedControl`] and a [`TabBar`]:
+/// the id of each tab, the selected index, the `on_change` callback and the indicator.
+struct Tabs(Vec<ElementID>, usize, Box<dyn FnMut(&mut Context, usize)>, Arc<Mutex<Indicator>>);
+
+impl Tabs {
+    fn new(ctx: &mut Context, labels: Vec<&str>, selected: usize, width: ButtonWidth, on_change: Box<dyn FnMut(&mut Context, usize)>) -> (Self, Vec<Button>) {
+        let (ids, mut buttons): (Vec<_>, Vec<_>) = labels.into_iter().enumerate().map(|(i, label)| {
+            let id = ElementID::new();
+            let state = if i == selected {ButtonState::Selected} else {ButtonState::UnSelected};
+            let button = Button::new(
+                ctx, None, None, Some(label), None, ButtonSize::Medium, width, ButtonStyle::Ghost,
+                state, Offset::Center, move |ctx: &mut Context| ctx.trigger_event(TabSelect(id)), None
+            );
+            (id, button)
+        }).unzip();
+
+        let tabs = Tabs(ids, selected, on_change, Arc::new(Mutex::new(Indicator::new(selected))));
+        tabs.color(ctx, &mut buttons);
+        (tabs, buttons)
+    }
+
+    /// Selects the tab with `id` if it is one of these tabs and calls `on_change`. Returns `true` if the selection changed.
+    fn pressed(&mut self, ctx: &mut Context, buttons: &mut [Button], id: ElementID) -> bool {
+        match self.0.iter().position(|i| *i == id) {
+            Some(index) if index != self.1 => {
+                self.select(ctx, buttons, index);
+                (self.2)(ctx, index);
+                true
+            },
+            _ => false
+        }
+    }
+
+    fn select(&mut self, ctx: &mut Context, buttons: &mut [Button], index: usize) {
+        if index == self.1 || index >= buttons.len() { return; }
+        self.1 = index;
+        self.3.lock().unwrap().select(index);
+        buttons.iter_mut().enumerate().for_each(|(i, button)| {
+            *button.status() = if i == index {ButtonState::Selected} else {ButtonState::UnSelected};
+        });
+        self.color(ctx, buttons);
+    }
+
+    fn tick(&mut self, ctx: &mut Context, buttons: &mut [Button]) {
+        self.3.lock().unwrap().tick();
+        // Hovering an unselected tab leaves it in the default state, so put it back to unselected.
+        let mut changed = false;
+        buttons.iter_mut().enumerate().filter(|(i, _)| *i != self.1).for_each(|(_, button)| {
+            if *button.status() == ButtonState::Default {
+                *button.status() = ButtonState::UnSelected;
+                changed = true;
+            }
+        });
+        if changed { self.color(ctx, buttons); }
+    }
+
+    /// Colors the tabs from their states. The selected tab is drawn on the indicator, so its own background is cleared.
+    fn color(&self, ctx: &mut Context, buttons: &mut [Button]) {
+        buttons.iter_mut().enumerate().for_each(|(i, button)| {
+            button.color(ctx);
+            if i == self.1 {
+                *button.background().background() = Color(0, 0, 0, 0);
+                *button.background().outline() = Color(0, 0, 0, 0);
+            }
+        });
+    }
+}
+
+/// Where the selection indicator is, and where it is sliding to.
+#[derive(Debug)]
+struct Indicator {
+    from: usize,
+    to: usize,
+    start: Option<Instant>,
+    progress: f32,
+    /// The offset and width of every tab from the last layout.
+    rects: Vec<(f32, f32)>,
+}
+
+impl Indicator {
+    const DURATION: f32 = 0.2;
+
+    fn new(index: usize) -> Self {
+        Indicator{from: index, to: index, start: None, progress: 1.0, rects: Vec::new()}
+    }
+
+    fn select(&mut self, index: usize) {
+        self.from = self.to;
+        self.to = index;
+        self.start = Some(Instant::now());
+        self.progress = 0.0;
+    }
+
+    fn tick(&mut self) {
+        let Some(start) = self.start else { return; };
+        let duration = if crate::config::reduce_motion() {0.0} else {Self::DURATION};
+        self.progress = if duration > 0.0 {(start.elapsed().as_secs_f32() / duration).min(1.0)} else {1.0};
+        if self.progress >= 1.0 {
+            self.start = None;
+            self.from = self.to;
+        }
+    }
+
+    /// Returns the offset and width of the indicator.
+    fn rect(&self) -> (f32, f32) {
+        let to = self.rects.get(self.to).copied().unwrap_or((0.0, 0.0));
+        let from = self.rects.get(self.from).copied().unwrap_or(to);
+        let p = 1.0 - (1.0 - self.progress).powi(3);
+        (from.0 + ((to.0 - from.0) * p), from.1 + ((to.1 - from.1) * p))
+    }
+}
@@ -1,8 +1,8 @@
-use pelican_ui::{Align, Area, Color, Component, Context, Drawable, Event, Layout, MouseEvent, MouseState, OnEvent, SizeRequest, TickEvent, KeyboardState, KeyboardEvent};
+use pelican_ui::{Align, Area, Color, Component, Context, Drawable, Event, Layout, MouseEvent, MouseState, OnEvent, SizeRequest, TickEvent, KeyboardState, KeyboardEvent, Key, NamedKey};
 
 use crate::elements::{OutlinedRectangle, ExpandableText, Text, TextStyle, TextEditor};
 use crate::components::IconButton;
-use crate::events::{SearchEvent, InputEditedEvent, KeyboardActiveEvent, SetActiveInput, TextInputSelect, ClearActiveInput};
+use crate::events::{SearchEvent, InputEditedEvent, KeyboardActiveEvent, SetActiveInput, SetInputValue, TextInputSelect, ClearActiveInput, ScrollToEvent};
 use crate::layout::{EitherOr, Padding, Column, Stack, Offset, Size, Row, Bin};
 use crate::utils::ElementID;
 
@@ -55,11 +55,15 @@ impl TextInput {
     }
 
     pub fn set_error(&mut self, ctx: &mut Context, error: &str) {
-        let font_size = ctx.theme.fonts.size.sm;
-        self.4 = Some(Text::new(ctx, error, TextStyle::Error, font_size, Align::Left));
+        self.4 = Some(error_text(ctx, error));
         self.3 = None;
     }
 
+    /// Removes the error set with [`TextInput::set_error`].
+    pub fn clear_error(&mut self) {
+        self.4 = None;
+    }
+
     pub fn set_help(&mut self, ctx: &mut Context, help: &str) {
         let font_size = ctx.theme.fonts.size.sm;
         self.3 = Some(ExpandableText::new(ctx, help, TextStyle::Secondary, font_size, Align::Left, None));
@@ -83,6 +87,11 @@ impl TextInput {
         changed
     }
 
+    /// Sets the icon button and its action, e.g. a picker that writes back with [`SetInputValue`] and the [`TextInput::get_id`] of this input.
+    pub fn set_icon_button(&mut self, ctx: &mut Context, icon: &'static str, on_click: impl FnMut(&mut Context, &mut String) + 'static) {
+        self.2.2.set_icon_button(ctx, icon, on_click);
+    }
+
     pub fn get_id(&self) -> ElementID { self.2.5 }
     pub fn status(&mut self) -> &mut InputState {self.2.status()}
 }
@@ -91,11 +100,19 @@ impl OnEvent for TextInput {
     fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
         if let Some(TickEvent) = event.downcast_ref::<TickEvent>() {
             *self.2.error() = self.4.is_some();
+        } else if let Some(scroll_to) = event.downcast_ref::<ScrollToEvent>() {
+            scroll_to.report(self.get_id());
         }
         true
     }
 }
 
+/// The error shown under an input, also used by inputs without a text field like [`AmountInput`](crate::AmountInput).
+pub(crate) fn error_text(ctx: &mut Context, error: &str) -> Text {
+    let font_size = ctx.theme.fonts.size.sm;
+    Text::new(ctx, error, TextStyle::Error, font_size, Align::Left)
+}
+
 #[derive(Debug, Component)]
 struct InputField(Stack, OutlinedRectangle, InputContent, #[skip] InputState, #[skip] bool, #[skip] ElementID, #[skip] bool);
 
@@ -142,6 +159,11 @@ impl OnEvent for InputField {
             // self.3 = if *self.error() { InputState::Error } else { InputState::Default };
         } else if let Some(SetActiveInput(s)) = event.downcast_ref::<SetActiveInput>() {
             *self.input() = s.to_string();
+        } else if let Some(SetInputValue(id, value)) = event.downcast_ref::<SetInputValue>() {
+            if *id == self.5 {
+                *self.input() = value.to_string();
+                ctx.trigger_event(InputEditedEvent);
+            }
         } else if let Some(TextInputSelect(id)) = event.downcast_ref::<TextInputSelect>() {
             if *id != self.5 && self.3 == InputState::Focus {
                 if self.4 { self.3 = InputState::Error } else { self.3 = InputState::Default }
@@ -192,10 +214,16 @@ impl OnEvent for InputField {
             }.unwrap_or(self.3);
         } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key}) = event.downcast_ref() {
             if self.3 == InputState::Focus {
-                self.2.text().apply_edit(ctx, key);
+                match key {
+                    // Escape leaves the input, the page only goes back on the next press.
+                    Key::Named(NamedKey::Escape) => self.3 = if self.4 { InputState::Error } else { InputState::Default },
+                    key => self.2.text().apply_edit(ctx, key),
+                }
             }
             ctx.trigger_event(InputEditedEvent);
         }
+        // Runs on every tick too, so the record the interface clears each frame is set again while focused.
+        crate::config::set_input_focus(self.5, self.3 == InputState::Focus);
         true
     }
 }
@@ -216,15 +244,7 @@ impl InputContent {
         icon_button: Option<(&'static str, impl FnMut(&mut Context, &mut String) + 'static)>,
     ) -> Self {
         let font_size = ctx.theme.fonts.size.md;
-        let (icon_button, callback) = icon_button.map(|(icon, on_click)| {
-            let (sender, receiver) = mpsc::channel();
-            (
-                Some(IconButton::input(ctx, icon, move |_| {sender.send(0).unwrap();})),
-                Some((receiver, Box::new(on_click) as SubmitCallback)),
-            )
-        }).unwrap_or((None, None));
-
-        InputContent(
+        let mut content = InputContent(
             Row::new(0.0, Offset::End, Size::Fit, Padding(16.0, 8.0, 8.0, 8.0)),
             Bin(
                 Stack(Offset::default(), Offset::End, Size::fill(), Size::Fit, Padding(8.0, 8.0, 8.0, 8.0)),
@@ -233,10 +253,18 @@ impl InputContent {
                     ExpandableText::new(ctx, placeholder, TextStyle::Secondary, font_size, Align::Left, None)
                 )
             ),
-            icon_button,
+            None,
             false,
-            callback,
-        )
+            None,
+        );
+        if let Some((icon, on_click)) = icon_button { content.set_icon_button(ctx, icon, on_click); }
+        content
+    }
+
+    fn set_icon_button(&mut self, ctx: &mut Context, icon: &'static str, on_click: impl FnMut(&mut Context, &mut String) + 'static) {
+        let (sender, receiver) = mpsc::channel();
+        self.2 = Some(IconButton::input(ctx, icon, move |_| {sender.send(0).unwrap();}));
+        self.4 = Some((receiver, Box::new(on_click) as SubmitCallback));
     }
 
     fn text(&mut self) -> &mut TextEditor { self.1.inner().left() }
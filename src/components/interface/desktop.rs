@@ -5,27 +5,35 @@ use pelican_ui::{
 };
 
 use crate::elements::{Rectangle, AspectRatioImage};
-use crate::events::{NavigatorSelect, NavigateEvent, NavigatorEvent};
+use crate::events::{NavigatorSelect, NavigatorEvent};
 use crate::layout::{Column, Stack, Bin, Row, Padding, Offset, Size};
 use crate::components::{Button, ButtonState, Avatar, AvatarContent};
 use crate::utils::ElementID;
 use crate::pages::AppPage;
-use crate::pages::Error;
 
 use std::fmt::Debug;
-use super::{NavigationButton, NavigateInfo, PageBuilder};
+use super::{NavigationButton, NavigateInfo, NavigatorTabs, PageHost, Tooltip};
 
 #[derive(Component)]
-pub struct DesktopInterface(Row, Option<DesktopNavigator>, Bin<Stack, Rectangle>, Option<Box<dyn AppPage>>, #[skip] PageBuilder);
+pub struct DesktopInterface(Row, Option<DesktopNavigator>, Bin<Stack, Rectangle>, PageHost, #[skip] Option<NavigatorTabs>);
 
 impl DesktopInterface {
     pub fn new(
         ctx: &mut Context, 
         start_page: Box<dyn AppPage>,
         mut navigation: Option<(usize, Vec<NavigateInfo>, Vec<NavigateInfo>)>,
+    ) -> Self {
+        let tabs = navigation.as_mut().map(NavigatorTabs::new);
+        Self::with_host(ctx, PageHost::new(start_page), tabs, navigation)
+    }
+
+    pub(crate) fn with_host(
+        ctx: &mut Context, 
+        host: PageHost,
+        tabs: Option<NavigatorTabs>,
+        navigation: Option<(usize, Vec<NavigateInfo>, Vec<NavigateInfo>)>,
     ) -> Self {
         let color = ctx.theme.colors.outline.secondary;
-        let pages = navigation.as_mut().map(|navi| navi.1.iter_mut().chain(navi.2.iter_mut()).map(|t| t.3.take().unwrap()).collect::<Vec<_>>());
         let navigator = navigation.map(|n| DesktopNavigator::new(ctx, n));
 
         DesktopInterface(
@@ -35,24 +43,24 @@ impl DesktopInterface {
                 Stack(Offset::default(), Offset::default(), Size::Static(1.0), Size::Fit, Padding::default()), 
                 Rectangle::new(color, 0.0)
             ),
-            Some(start_page),
-            pages
+            host,
+            tabs
         )
     }
 
-    pub fn page(&mut self) -> &mut Option<Box<dyn AppPage>> { &mut self.3 }
+    pub fn page(&mut self) -> &mut Option<Box<dyn AppPage>> { self.3.page() }
+    pub fn host(&mut self) -> &mut PageHost { &mut self.3 }
+    pub fn tabs(&mut self) -> Option<&mut NavigatorTabs> { self.4.as_mut() }
     pub fn navigator(&mut self) -> &mut Option<DesktopNavigator> { &mut self.1 }
+
+    /// Consumes the interface, returning its pages and the navigator's tabs.
+    pub fn into_parts(self) -> (PageHost, Option<NavigatorTabs>) { (self.3, self.4) }
 }
 
 impl OnEvent for DesktopInterface {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
-        if let Some(NavigateEvent(index)) = event.downcast_mut::<NavigateEvent>() {
-            self.3 = match self.3.take().unwrap().navigate(ctx, *index) {
-                Ok(p) => Some(p),
-                Err(e) => Some(Box::new(Error::new(ctx, "404 Page Not Found", e)))
-            };
-        } else if let Some(NavigatorEvent(index)) = event.downcast_mut::<NavigatorEvent>() {
-            if let Some(nav) = self.4.as_mut() { self.3 = Some(nav[*index](ctx)); }
+        if let Some(NavigatorEvent(index)) = event.downcast_mut::<NavigatorEvent>() {
+            if let Some(tabs) = self.4.as_mut() { tabs.select(ctx, *index, &mut self.3); }
         }
         true
     }
@@ -83,10 +91,10 @@ impl DesktopNavigator {
 
             if let Some(avatar) = avatar {
                 let profile = Button::photo(ctx, &name, avatar, navigation.0 == index, closure);
-                top_col.push(NavigationButton::new(id, Some(profile), None))
+                top_col.push(Tooltip::new(NavigationButton::new(id, Some(profile), None), &name))
             } else {
                 let button = Button::navigation(ctx, icon, &name, navigation.0 == index, closure);
-                top_col.push(NavigationButton::new(id, Some(button), None))
+                top_col.push(Tooltip::new(NavigationButton::new(id, Some(button), None), &name))
             }
 
             index += 1;
@@ -101,10 +109,10 @@ impl DesktopNavigator {
 
             if let Some(avatar) = avatar {
                 let profile = Button::photo(ctx, &name, avatar, navigation.0 == index, closure);
-                bot_col.push(NavigationButton::new(id, Some(profile), None))
+                bot_col.push(Tooltip::new(NavigationButton::new(id, Some(profile), None), &name))
             } else {
                 let button = Button::navigation(ctx, icon, &name, navigation.0 == index, closure);
-                bot_col.push(NavigationButton::new(id, Some(button), None))
+                bot_col.push(Tooltip::new(NavigationButton::new(id, Some(button), None), &name))
             }
 
             index += 1;
@@ -125,6 +133,7 @@ impl DesktopNavigator {
         )
     }
 
+    /// Changes the avatar of this navigator only. [`Interface::update_avatar`](crate::Interface::update_avatar) keeps it when the shell is switched.
     pub fn update_avatar(&mut self, avatar_content: AvatarContent) {
         if let Some(avatar) = self.avatar() {
             if avatar.avatar().image().is_none() {
@@ -137,16 +146,17 @@ impl DesktopNavigator {
         };
     }
 
+    /// Changes the name of this navigator only. [`Interface::update_username`](crate::Interface::update_username) keeps it when the shell is switched.
     pub fn update_username(&mut self, username: String) {
         self.4.buttons()[0].button().as_mut().unwrap().label().as_mut().unwrap().text().spans[0].text = username;
     }
 
     pub fn avatar(&mut self) -> Option<&mut Avatar> {
-        self.4.buttons().iter_mut().flat_map(|nb| nb.button()).flat_map(|button| button.avatar()).next()
+        self.4.buttons().into_iter().flat_map(|nb| nb.button()).flat_map(|button| button.avatar()).next()
     }
 
     pub fn buttons(&mut self) -> Vec<&mut Button> {
-        self.2.buttons().iter_mut().flat_map(|nb| nb.button()).collect::<Vec<_>>()
+        self.2.buttons().into_iter().flat_map(|nb| nb.button()).collect::<Vec<_>>()
     }
 }
 
@@ -154,8 +164,8 @@ impl OnEvent for DesktopNavigator {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
         if let Some(NavigatorSelect(id)) = event.downcast_ref::<NavigatorSelect>() {
             println!("Navigator selected");
-            let mut buttons: Vec<&mut NavigationButton> = self.2.buttons().iter_mut().collect();
-            buttons.extend(self.4.buttons().iter_mut());
+            let mut buttons: Vec<&mut NavigationButton> = self.2.buttons();
+            buttons.extend(self.4.buttons());
             buttons.iter_mut().for_each(|button| {
                 *button.button().as_mut().unwrap().status() = if button.id() == *id {ButtonState::Selected} else {ButtonState::Default};
                 button.button().as_mut().unwrap().color(ctx);
@@ -165,14 +175,17 @@ impl OnEvent for DesktopNavigator {
     }
 }
 
+/// The navigation buttons of a [`DesktopNavigator`], each with a [`Tooltip`] naming its page.
 #[derive(Debug, Component)]
-struct ButtonColumn(Column, Vec<NavigationButton>);
+struct ButtonColumn(Column, Vec<Tooltip>);
 impl OnEvent for ButtonColumn {}
 
 impl ButtonColumn {
-    fn new(buttons: Vec<NavigationButton>) -> Self {
+    fn new(buttons: Vec<Tooltip>) -> Self {
         ButtonColumn(Column::center(8.0), buttons)
     }
 
-    fn buttons(&mut self) -> &mut Vec<NavigationButton> {&mut self.1}
+    fn buttons(&mut self) -> Vec<&mut NavigationButton> {
+        self.1.iter_mut().filter_map(|tooltip| tooltip.content().as_any_mut().downcast_mut::<NavigationButton>()).collect()
+    }
 }
@@ -2,28 +2,42 @@ use pelican_ui::{
     Align, Area, Component, Context,
     Drawable, Event, Layout,
     MouseEvent, MouseState, OnEvent,
-    SizeRequest,
+    SizeRequest, TickEvent,
+    KeyboardEvent, KeyboardState, Key, NamedKey,
 };
 
 use crate::elements::{Rectangle, TextStyle, Text};
-use crate::events::{TextInputSelect, AdjustScrollEvent};
+use crate::events::{TextInputSelect, AdjustScrollEvent, ScrollToEvent, ScrollChangedEvent, StickyEvent};
 use crate::layout::{Column, Stack, Row, Padding, Offset, Size, Scroll, ScrollAnchor};
-use crate::components::{AvatarContent, IconButton, Button, TextInput};
+use crate::components::{Alert, AvatarContent, IconButton, Button, TextInput, MessageComposer, LazyList};
 use crate::utils::ElementID;
 use crate::pages::AppPage;
+use crate::config::InterfaceMode;
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
 
-use super::{DesktopInterface, MobileInterface, WebInterface};
+use super::toast::toast_area;
+use super::tooltip::tooltip_area;
+use super::popover::Rect;
+use super::{DesktopInterface, MobileInterface, WebInterface, NavigatorTabs, PageHost, ModalLayer, ModalResult, PopoverLayer, Tooltip, TooltipLayer, ToastLayer};
 
 pub type NavigateInfo = (&'static str, String, Option<AvatarContent>, Option<Box<dyn FnMut(&mut Context) -> Box<dyn AppPage>>>);
-pub type PageBuilder = Option<Vec<Box<dyn FnMut(&mut Context) -> Box<dyn AppPage>>>>;
 
 /// The top-level interface of an app built with Pelican.
 ///
-/// This interface automatically adapts to the platform:
-/// - On desktop, it uses [`DesktopInterface`].
-/// - On web, it uses [`WebInterface`].
-/// - On mobile, it uses [`MobileInterface`].
+/// This interface adapts to the window size using the current [`Breakpoints`](crate::Breakpoints):
+/// - On mobile, or in windows narrower than `compact`, it uses [`MobileInterface`].
+/// - Otherwise, on web it uses [`WebInterface`].
+/// - Otherwise, it uses [`DesktopInterface`].
+///
+/// The shell is switched live when the window is resized. The current page and the selected
+/// navigator tab are kept across the switch.
+///
+/// Each navigator tab keeps its own pages and history, see [`NavigatorTabs`].
+///
+/// [`Modal`](crate::Modal)s presented with a [`PresentModalEvent`](crate::PresentModalEvent) are shown above every shell,
+/// [`Popover`](crate::Popover)s shown with a [`PresentPopoverEvent`](crate::PresentPopoverEvent) above those,
+/// hints of [`Tooltip`](crate::Tooltip)s above those, and [`Toast`](crate::Toast)s shown with a [`ToastEvent`](crate::ToastEvent) above everything.
 ///
 /// The background color is taken from `ctx.theme.colors.background.primary` by default.
 /// You can customize it by setting ctx.theme to a customized [`Theme`] object.
@@ -38,8 +52,42 @@ pub type PageBuilder = Option<Vec<Box<dyn FnMut(&mut Context) -> Box<dyn AppPage
 ///     On web and mobile, these vectors are combined with no visual separation.
 /// - A vector of socials for web, as tuples `(icon, URL)` representing the social icon and its link.
 #[derive(Debug, Component)]
-pub struct Interface (Stack, Option<Rectangle>, Option<MobileInterface>, Option<DesktopInterface>, Option<WebInterface>);
-impl OnEvent for Interface {}
+pub struct Interface (WindowLayout, Option<Rectangle>, Option<MobileInterface>, Option<DesktopInterface>, Option<WebInterface>, ModalLayer, PopoverLayer, TooltipLayer, ToastLayer, #[skip] InterfaceState);
+
+impl OnEvent for Interface {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            // Focused inputs record themselves again as the tick reaches them.
+            crate::config::clear_input_focus();
+            let (width, _) = *self.0.1.lock().unwrap();
+            if width > 0.0 {
+                let mode = crate::config::breakpoints().mode(width);
+                if mode != self.9.mode { self.switch(ctx, mode); }
+            }
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(NamedKey::Escape | NamedKey::BrowserBack | NamedKey::GoBack)}) = event.downcast_ref::<KeyboardEvent>() {
+            // Back closes the popover or the top-most modal instead of the page underneath.
+            // A focused input in it gets the first Escape to leave the input, like on a page.
+            self.7.dismiss();
+            if !crate::config::input_focused() {
+                if self.6.is_active() {
+                    self.6.dismiss();
+                    return false;
+                } else if self.5.is_active() {
+                    self.5.dismiss(ModalResult::Dismissed);
+                    return false;
+                }
+            }
+        }
+
+        if event.downcast_ref::<KeyboardEvent>().is_some() {
+            // Typing goes to the popover or modal, not to the page under the scrim.
+            let hold = self.5.is_active() || self.6.is_active();
+            if let Some(host) = self.host() { host.hold_keys(hold); }
+        }
+        true
+    }
+}
+
 impl Interface {
     pub fn new(
         ctx: &mut Context, 
@@ -48,54 +96,165 @@ impl Interface {
         socials: Option<Vec<(&'static str, String)>>
     ) -> Self {
         let color = ctx.theme.colors.background.primary;
-
-        let (mobile, desktop, web) = match crate::config::IS_WEB {
-            true => (None, None, Some(WebInterface::new(ctx, start_page, navigation, socials))),
-            false if crate::config::IS_MOBILE => (Some(MobileInterface::new(ctx, start_page, navigation)), None, None),
-            false => (None, Some(DesktopInterface::new(ctx, start_page, navigation)), None),
+        let mode = InterfaceMode::platform();
+        let state = InterfaceState::new(mode, &navigation, socials.clone());
+        crate::config::set_interface_mode(mode);
+
+        let (mobile, desktop, web) = match mode {
+            InterfaceMode::Web => (None, None, Some(WebInterface::new(ctx, start_page, navigation, socials))),
+            InterfaceMode::Mobile => (Some(MobileInterface::new(ctx, start_page, navigation)), None, None),
+            InterfaceMode::Desktop => (None, Some(DesktopInterface::new(ctx, start_page, navigation)), None),
         };
 
-        Interface(Stack::default(), Some(Rectangle::new(color, 0.0)), mobile, desktop, web)
-    }
-
-    // //move background to pages
-    // pub fn new_with_background(
-    //     ctx: &mut Context, 
-    //     image: resources::Image,
-    //     start_page: Box<dyn AppPage>,
-    //     navigation: Option<(usize, Vec<NavigateInfo>, Vec<NavigateInfo>)>,
-    //     socials: Option<Vec<(&'static str, String)>>
-    // ) -> Self {
-    //     let background = ExpandableImage::new(image, None);
-    //     let (mobile, desktop, web) = match crate::config::IS_WEB {
-    //         true => (None, None, Some(WebInterface::new(ctx, start_page, navigation, socials))),
-    //         false if crate::config::IS_MOBILE => (Some(MobileInterface::new(ctx, start_page, navigation)), None, None),
-    //         false => (None, Some(DesktopInterface::new(ctx, start_page, navigation)), None),
-    //     };
-    //     Interface(Stack::default(), None, Some(background), mobile, desktop, web)
-    // }
-
-    /// Returns the DesktopInterface if on desktop
+        let tooltips = TooltipLayer::new();
+        Interface(WindowLayout::new(tooltips.anchor()), Some(Rectangle::new(color, 0.0)), mobile, desktop, web, ModalLayer::new(), PopoverLayer::new(), tooltips, ToastLayer::new(), state)
+    }
+
+    /// Returns the DesktopInterface if the desktop shell is showing
     pub fn desktop(&mut self) -> &mut Option<DesktopInterface> { &mut self.3 }
-    /// Returns the MobileInterface if on mobile
+    /// Returns the MobileInterface if the mobile shell is showing
     pub fn mobile(&mut self) -> &mut Option<MobileInterface> { &mut self.2 }
-    /// Returns the WebInterface if on web
+    /// Returns the WebInterface if the web shell is showing
     pub fn web(&mut self) -> &mut Option<WebInterface> { &mut self.4 }
-    // pub fn navigation(&mut self) -> (Option<&mut Option<MobileNavigator>>, Option<&mut Option<DesktopNavigator>>) {
-    //     (self.desktop().as_mut().map(|d| &mut d.navigator()), self.mobile().as_mut().map(|m| &mut m.navigator()))
-    // }
+    /// Returns the shell that is currently showing.
+    pub fn mode(&self) -> InterfaceMode { self.9.mode }
+    /// Returns the layer presenting [`Modal`](crate::Modal)s over the page.
+    pub fn modals(&mut self) -> &mut ModalLayer { &mut self.5 }
+    /// Returns the layer showing [`Popover`](crate::Popover)s next to their anchors.
+    pub fn popovers(&mut self) -> &mut PopoverLayer { &mut self.6 }
+    /// Returns the layer showing the hints of [`Tooltip`](crate::Tooltip)s.
+    pub fn tooltips(&mut self) -> &mut TooltipLayer { &mut self.7 }
+    /// Returns the layer showing [`Toast`](crate::Toast)s above the page.
+    pub fn toasts(&mut self) -> &mut ToastLayer { &mut self.8 }
+    /// Returns the current page.
+    pub fn page(&mut self) -> Option<&mut Box<dyn AppPage>> {
+        self.host()?.page().as_mut()
+    }
+    /// Returns the pages of the navigator tabs, e.g. to set which tabs reset when selected.
+    pub fn tabs(&mut self) -> Option<&mut NavigatorTabs> {
+        match (&mut self.2, &mut self.3, &mut self.4) {
+            (Some(mobile), _, _) => mobile.tabs(),
+            (_, Some(desktop), _) => desktop.tabs(),
+            (_, _, Some(web)) => web.tabs(),
+            _ => None
+        }
+    }
+    /// Returns the current page and its navigation history.
+    pub fn host(&mut self) -> Option<&mut PageHost> {
+        match (&mut self.2, &mut self.3, &mut self.4) {
+            (Some(mobile), _, _) => Some(mobile.host()),
+            (_, Some(desktop), _) => Some(desktop.host()),
+            (_, _, Some(web)) => Some(web.host()),
+            _ => None
+        }
+    }
+
+    /// Changes the name on the first button at the bottom of the navigator, usually the profile.
+    /// The name is kept when the shell is switched.
+    pub fn update_username(&mut self, username: String) {
+        if let Some((_, name, _)) = self.9.tabs.as_mut().and_then(|(_, bottom)| bottom.first_mut()) { *name = username.clone(); }
+        if let Some(navigator) = self.3.as_mut().and_then(|desktop| desktop.navigator().as_mut()) { navigator.update_username(username); }
+    }
+
+    /// Changes the avatar on the first button at the bottom of the navigator that has one.
+    /// The avatar is kept when the shell is switched.
+    pub fn update_avatar(&mut self, avatar_content: AvatarContent) {
+        let label = self.9.tabs.as_mut().and_then(|(_, bottom)| bottom.iter_mut().find(|(_, _, avatar)| avatar.is_some()));
+        if let Some((_, _, avatar)) = label { *avatar = Some(avatar_content.clone()); }
+        if let Some(navigator) = self.3.as_mut().and_then(|desktop| desktop.navigator().as_mut()) { navigator.update_avatar(avatar_content); }
+    }
+
+    /// Rebuilds the interface in the given shell, moving the current page and navigator over.
+    fn switch(&mut self, ctx: &mut Context, mode: InterfaceMode) {
+        let parts = self.2.take().map(MobileInterface::into_parts)
+            .or_else(|| self.3.take().map(DesktopInterface::into_parts))
+            .or_else(|| self.4.take().map(WebInterface::into_parts));
+        let Some((host, tabs)) = parts else { return; };
+
+        let navigation = self.9.navigation(tabs.as_ref().map(|t| t.current()).unwrap_or(0));
+        self.9.mode = mode;
+        crate::config::set_interface_mode(mode);
+
+        match mode {
+            InterfaceMode::Web => self.4 = Some(WebInterface::with_host(ctx, host, tabs, navigation, self.9.socials.clone())),
+            InterfaceMode::Mobile => self.2 = Some(MobileInterface::with_host(ctx, host, tabs, navigation)),
+            InterfaceMode::Desktop => self.3 = Some(DesktopInterface::with_host(ctx, host, tabs, navigation)),
+        }
+    }
 }  
 
+type NavigateLabel = (&'static str, String, Option<AvatarContent>);
+
+/// What the [`Interface`] needs to rebuild its navigator in another shell.
+#[derive(Debug)]
+struct InterfaceState {
+    mode: InterfaceMode,
+    tabs: Option<(Vec<NavigateLabel>, Vec<NavigateLabel>)>,
+    socials: Option<Vec<(&'static str, String)>>,
+}
+
+impl InterfaceState {
+    fn new(mode: InterfaceMode, navigation: &Option<(usize, Vec<NavigateInfo>, Vec<NavigateInfo>)>, socials: Option<Vec<(&'static str, String)>>) -> Self {
+        let labels = |info: &Vec<NavigateInfo>| info.iter().map(|(icon, name, avatar, _)| (*icon, name.clone(), avatar.clone())).collect::<Vec<_>>();
+        InterfaceState {
+            mode,
+            tabs: navigation.as_ref().map(|n| (labels(&n.1), labels(&n.2))),
+            socials,
+        }
+    }
+
+    /// Rebuilds the navigation without page builders, which stay in the [`NavigatorTabs`].
+    fn navigation(&self, selected: usize) -> Option<(usize, Vec<NavigateInfo>, Vec<NavigateInfo>)> {
+        let (top, bottom) = self.tabs.as_ref()?;
+        let info = |labels: &Vec<NavigateLabel>| labels.iter().map(|(icon, name, avatar)| 
+            (*icon, name.clone(), avatar.clone(), None)
+        ).collect::<Vec<_>>();
+        Some((selected, info(top), info(bottom)))
+    }
+}
+
+/// A [`Stack`] that records the size of the window it is built in.
+/// The last child is the [`ToastLayer`], which is placed at the edge of the window instead,
+/// and the one before it the [`TooltipLayer`], which is placed next to the anchor of its tooltip.
+#[derive(Debug)]
+struct WindowLayout(Stack, Arc<Mutex<(f32, f32)>>, Arc<Mutex<Option<Rect>>>);
+
+impl WindowLayout {
+    fn new(tooltip: Arc<Mutex<Option<Rect>>>) -> Self {
+        WindowLayout(Stack::default(), Arc::new(Mutex::new((0.0, 0.0))), tooltip)
+    }
+}
+
+impl Layout for WindowLayout {
+    fn request_size(&self, ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        self.0.request_size(ctx, children)
+    }
+
+    fn build(&self, ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        *self.1.lock().unwrap() = size;
+        let toasts = children.last().map(|request| toast_area(ctx, size, request));
+        let anchor = *self.2.lock().unwrap();
+        let tooltips = children.len().checked_sub(2).map(|i| tooltip_area(size, &children[i], anchor));
+        let mut areas = self.0.build(ctx, size, children);
+        let count = areas.len();
+        if let (Some(area), Some(toasts)) = (areas.last_mut(), toasts) { *area = toasts; }
+        if let (Some(area), Some(tooltips)) = (count.checked_sub(2).and_then(|i| areas.get_mut(i)), tooltips) { *area = tooltips; }
+        areas
+    }
+}
+
 /// # Page
 ///
 /// A Page is a UI container that holds optional [`Header`], [`Content`], and optional [`Bumper`] components.
 ///
+/// A page can also show a full-width [`Alert`] banner between the header and the content with [`Page::set_banner`].
+///
 /// <img src="https://raw.githubusercontent.com/ramp-stack/pelican_ui_std/main/src/examples/page.png"
 ///      alt="Page Example"
 ///      width="250">
 
 #[derive(Debug, Component)]
-pub struct Page(Column, Option<Header>, Content, Option<Bumper>);
+pub struct Page(Column, Option<Header>, Option<Alert>, Content, Option<Bumper>);
 impl OnEvent for Page {}
 
 impl Page {
@@ -105,17 +264,25 @@ impl Page {
         Page(
             Column::new(12.0, Offset::Center, width, Padding::default()),
             header,
+            None,
             content,
             bumper,
         )
     }
 
+    /// Shows `alert` as a banner under the header, or removes the banner with `None`.
+    pub fn set_banner(&mut self, ctx: &mut Context, alert: Option<Alert>) {
+        self.2 = alert.map(|alert| alert.banner(ctx));
+    }
+
     /// Returns the header if it exists.
     pub fn header(&mut self) -> &mut Option<Header> {&mut self.1}
+    /// Returns the banner if it exists.
+    pub fn banner(&mut self) -> &mut Option<Alert> {&mut self.2}
     /// Returns the content.
-    pub fn content(&mut self) -> &mut Content {&mut self.2}
+    pub fn content(&mut self) -> &mut Content {&mut self.3}
     /// Returns the bumper if it exists.
-    pub fn bumper(&mut self) -> &mut Option<Bumper> {&mut self.3}
+    pub fn bumper(&mut self) -> &mut Option<Bumper> {&mut self.4}
 }
 
 /// # Content
@@ -134,21 +301,28 @@ impl Page {
 /// let text = Text::new(ctx, "Set up a name, description, and team before starting your project.", TextStyle::Primary, text_size, Align::Center);
 /// let content = Content::new(ctx, Offset::Center, vec![Box::new(text)]);
 /// ```
+///
+/// Content triggers a [`ScrollChangedEvent`] carrying its [`ElementID`] whenever it is scrolled,
+/// and scrolls to elements requested through a [`ScrollToEvent`].
 #[derive(Debug, Component)]
-pub struct Content (Scroll, ContentChildren);
+pub struct Content (Scroll, ContentChildren, #[skip] ElementID, #[skip] Option<ScrollRequest>, #[skip] Option<(f32, f32)>);
+
+type ScrollRequest = (Arc<Mutex<Option<(f32, f32)>>>, Offset, bool);
 
 impl Content {
     /// Creates a new `Content` component with a specified `Offset` (start, center, or end) and a list of `Box<dyn Drawable>` children.
     pub fn new(ctx: &mut Context, offset: Offset, content: Vec<Box<dyn Drawable>>) -> Self {
         let max = ctx.theme.layout.content_max;
         let padding = ctx.theme.layout.content_padding;
-        let max = if crate::config::IS_WEB {1200.0} else {max};
-        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[0].0.min(max), max));
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|{
+            let max = crate::config::content_max(max);
+            (widths[0].0.min(max), max)
+        });
         let height = Size::custom(move |_: Vec<(f32, f32)>|(0.0, f32::MAX));
         let anchor = if offset == Offset::End { ScrollAnchor::End } else { ScrollAnchor::Start };
         let layout = Scroll::new(Offset::Center, offset, width, height, Padding::default(), anchor);
         // if offset == Offset::End { layout.set_scroll(f32::MAX); }
-        Content(layout, ContentChildren::new(content, padding)) 
+        Content(layout, ContentChildren::new(content, padding), ElementID::new(), None, None)
     }
 
     /// Find an item in the content. Will return the first instance of the type.
@@ -187,30 +361,56 @@ impl Content {
     pub fn items(&mut self) -> &mut Vec<Box<dyn Drawable>> {&mut self.1.1}
     /// Returns the offset of the items.
     pub fn offset(&mut self) -> &mut Offset {self.0.offset()}
+    /// Returns the id reported in this content's [`ScrollChangedEvent`]s.
+    pub fn id(&self) -> ElementID {self.2}
+    /// Returns the scroll layout, e.g. to change its [`ScrollPhysics`](crate::ScrollPhysics).
+    pub fn layout(&mut self) -> &mut Scroll {&mut self.0}
+
+    /// Returns `true` when the content holds a [`LazyList`], which scrolls its own rows.
+    fn scrolls_items(&self) -> bool {
+        self.1.1.iter().any(|item| item.as_any().is::<LazyList>())
+    }
 }
 
 impl OnEvent for Content {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
         if let Some(AdjustScrollEvent::Vertical(a)) = event.downcast_ref::<AdjustScrollEvent>() {
-            self.0.adjust_scroll(*a);
+            if !self.scrolls_items() { self.0.adjust_scroll(*a); }
         } else if let Some(TextInputSelect(id)) = event.downcast_ref::<TextInputSelect>() {
             if crate::config::IS_MOBILE {
-                let mut total_height = 0.0;
-                for item in self.items().iter_mut() {
-                    match item.as_any_mut().downcast_mut::<TextInput>() {
-                        Some(input) if input.get_id() == *id => {
-                            self.0.set_scroll(total_height);
-                            break;
-                        }
-                        _ => {
-                            let size = item.request_size(ctx);
-                            total_height += size.max_height();
-                        }
-                    }
-                }
+                ctx.trigger_event(ScrollToEvent::new(*id, Offset::Start, true));
             }
+        } else if let Some(scroll_to) = event.downcast_mut::<ScrollToEvent>() {
+            self.3 = Some((scroll_to.capture(), scroll_to.align, scroll_to.animated));
+        } else if let Some(sticky) = event.downcast_mut::<StickyEvent>() {
+            let position = self.0.position();
+            *sticky = StickyEvent(Some(position.clone()), position.built());
         } else if let Some(MouseEvent { state: MouseState::Scroll(_, y), position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
-            self.0.adjust_scroll(*y);
+            if !self.scrolls_items() { self.0.adjust_scroll(*y); }
+        } else if let Some(MouseEvent { state: MouseState::Pressed, position: Some(_) }) = event.downcast_ref::<MouseEvent>() {
+            self.0.hold();
+        } else if let Some(MouseEvent { state: MouseState::Released, .. }) = event.downcast_ref::<MouseEvent>() {
+            self.0.release();
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            if let Some((target, align, animated)) = self.3.take() {
+                if let Some((position, size)) = *target.lock().unwrap() {
+                    self.0.scroll_into_view(position, size, align, animated);
+                }
+            }
+
+            self.0.tick();
+
+            let (offset, max_offset) = (self.0.scroll_offset(), self.0.max_offset());
+            let changed = self.4.map(|(o, m)| (o - offset).abs() > 0.5 || (m - max_offset).abs() > 0.5).unwrap_or(true);
+            if changed {
+                self.4 = Some((offset, max_offset));
+                ctx.trigger_event(StickyEvent(None, 0.0));
+                ctx.trigger_event(ScrollChangedEvent{
+                    id: self.2, offset, max_offset,
+                    at_top: offset <= 0.5,
+                    at_bottom: offset >= max_offset - 0.5,
+                });
+            }
         }
         true
     }
@@ -285,6 +485,18 @@ impl Header {
         )
     }
 
+    /// Gives the left and right icons a [`Tooltip`] naming their action, e.g. "Back" or "Close".
+    ///
+    /// ```rust
+    /// let back = IconButton::navigation(ctx, "left", |ctx: &mut Context| println!("Go Back!"));
+    /// let header = Header::stack(ctx, Some(back), "Select role", None).tooltips(Some("Back"), None);
+    /// ```
+    pub fn tooltips(mut self, left: Option<&str>, right: Option<&str>) -> Self {
+        if let Some(label) = left { self.1.set_tooltip(label); }
+        if let Some(label) = right { self.3.set_tooltip(label); }
+        self
+    }
+
     pub fn content(&mut self) -> &mut HeaderContent {&mut self.2}
 }
 
@@ -338,18 +550,39 @@ impl HeaderContent {
 /// # Header Icon
 /// 
 /// Optionally contains an icon, otherwise just reserves the space.
+/// Use [`HeaderIcon::with_tooltip`] or [`Header::tooltips`] to give the icon a [`Tooltip`] naming its action.
 /// These are only to be used in [`Header`] components.
 #[derive(Debug, Component)]
-pub struct HeaderIcon(Stack, Option<IconButton>);
+pub struct HeaderIcon(Stack, Option<Box<dyn Drawable>>);
 impl OnEvent for HeaderIcon {}
 
 impl HeaderIcon {
     pub fn new(icon: Option<IconButton>) -> Self {
         HeaderIcon(
             Stack(Offset::Center, Offset::Center, Size::Static(48.0), Size::Static(48.0), Padding::default()),
-            icon
+            icon.map(|icon| Box::new(icon) as Box<dyn Drawable>)
         )
     }
+
+    /// A header icon with a [`Tooltip`] showing `label`.
+    ///
+    /// ```rust
+    /// let search = IconButton::navigation(ctx, "search", |ctx: &mut Context| println!("Search!"));
+    /// let icon = HeaderIcon::with_tooltip(search, "Search");
+    /// ```
+    pub fn with_tooltip(icon: IconButton, label: &str) -> Self {
+        let mut header_icon = HeaderIcon::new(None);
+        header_icon.1 = Some(Box::new(Tooltip::new(icon, label)));
+        header_icon
+    }
+
+    /// Gives the icon, if it is an [`IconButton`] without one, a [`Tooltip`] showing `label`.
+    fn set_tooltip(&mut self, label: &str) {
+        self.1 = self.1.take().map(|icon| match icon.as_any().is::<IconButton>() {
+            true => Box::new(Tooltip::new(*icon.into_any().downcast::<IconButton>().unwrap(), label)) as Box<dyn Drawable>,
+            false => icon,
+        });
+    }
 }
 
 /// # Bumper
@@ -377,8 +610,10 @@ impl Bumper {
     pub fn new(ctx: &mut Context, content: Vec<Box<dyn Drawable>>) -> Self {
         let background = ctx.theme.colors.background.primary;
         let max = ctx.theme.layout.bumper_max;
-        let max = if crate::config::IS_WEB {1200.0} else {max};
-        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[0].0.min(max), max));
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|{
+            let max = crate::config::content_max(max);
+            (widths[0].0.min(max), max)
+        });
         let height = Size::custom(move |heights: Vec<(f32, f32)>|(heights[1].0, heights[1].1));
         let layout = Stack(Offset::Center, Offset::Start, width, height, Padding::default());
         Bumper(layout, Rectangle::new(background, 0.0), BumperContent::new(content))
@@ -399,6 +634,11 @@ impl Bumper {
         Self::new(ctx, vec![Box::new(input)])
     }
 
+    /// Creates a `Bumper` from a [`MessageComposer`].
+    pub fn composer(ctx: &mut Context, composer: MessageComposer) -> Self {
+        Self::new(ctx, vec![Box::new(composer)])
+    }
+
     /// Returns the items in the `Bumper`.
     pub fn items(&mut self) -> &mut Vec<Box<dyn Drawable>> {
         &mut self.2.1
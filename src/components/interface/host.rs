@@ -0,0 +1,372 @@
+use pelican_ui::{
+    Area, Color, Component, Context,
+    Drawable, Event, Layout,
+    MouseEvent, OnEvent, SizeRequest, TickEvent,
+    KeyboardEvent, KeyboardState, Key, NamedKey,
+};
+
+use crate::elements::Rectangle;
+use crate::events::{NavigateEvent, NavigationEvent};
+use crate::layout::{Offset, Stack};
+use crate::pages::{AppPage, Error, Transition};
+
+use std::any::TypeId;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use super::NavigateInfo;
+
+/// # Page Host
+///
+/// Holds the current page of an interface shell together with its navigation history.
+///
+/// Pages pushed with [`NavigationEvent::Push`] keep the previous page alive in the back stack,
+/// so going back restores it exactly as it was left. Popped pages are kept in a forward stack
+/// until a new page is pushed.
+///
+/// The history is driven by:
+/// - [`NavigateEvent`], which replaces the current page with the result of [`AppPage::navigate`].
+/// - [`NavigationEvent`], which pushes, replaces and pops pages.
+/// - The Escape, browser back and hardware back keys, which pop the current page unless a text input
+///   has focus or the page opts out with [`AppPage::can_go_back`].
+///
+/// Page changes are animated with the [`Transition`] of the incoming page, or the reverse of the
+/// outgoing page's transition when going back. Both pages are drawn while the animation runs and
+/// mouse input is held back until it finishes.
+#[derive(Component)]
+pub struct PageHost(
+    HostLayout, Option<Box<dyn AppPage>>, Option<Box<dyn AppPage>>, Option<Box<dyn AppPage>>, Rectangle,
+    #[skip] Vec<Box<dyn AppPage>>, #[skip] Vec<Box<dyn AppPage>>, #[skip] Destination, #[skip] bool
+);
+
+impl PageHost {
+    pub fn new(page: Box<dyn AppPage>) -> Self {
+        PageHost(
+            HostLayout::default(), None, Some(page), None, Rectangle::new(Color(0, 0, 0, 0), 0.0),
+            Vec::new(), Vec::new(), Destination::Drop, false
+        )
+    }
+
+    /// Returns the current page.
+    pub fn page(&mut self) -> &mut Option<Box<dyn AppPage>> { &mut self.2 }
+    /// Returns the pages behind the current page, oldest first.
+    pub fn history(&mut self) -> &mut Vec<Box<dyn AppPage>> { &mut self.5 }
+    /// Returns `true` if there is a page to go back to.
+    pub fn can_go_back(&self) -> bool { !self.5.is_empty() }
+    /// Returns `true` if there is a page to go forward to.
+    pub fn can_go_forward(&self) -> bool { !self.6.is_empty() }
+    /// Returns whether the current page shows the navigation bar.
+    pub fn has_nav(&self) -> bool { self.2.as_ref().map(|p| p.has_nav()).unwrap_or(false) }
+    /// Keeps keyboard input from the pages while `hold` is `true`, e.g. while a modal covers them.
+    pub fn hold_keys(&mut self, hold: bool) { self.8 = hold; }
+    /// Returns `true` while a transition is playing.
+    pub fn is_animating(&self) -> bool { self.0.0.lock().unwrap().start.is_some() }
+
+    /// Shows `page`, keeping the current page in the history.
+    pub fn push(&mut self, page: Box<dyn AppPage>) {
+        self.finish();
+        self.6.clear();
+        let transition = page.transition();
+        self.show(page, Destination::Back, transition, false);
+    }
+
+    /// Shows `page` in place of the current page.
+    pub fn replace(&mut self, page: Box<dyn AppPage>) {
+        let transition = page.transition();
+        self.show(page, Destination::Drop, transition, false);
+    }
+
+    /// Shows `page` and clears the history.
+    pub fn reset(&mut self, page: Box<dyn AppPage>) {
+        self.finish();
+        self.5.clear();
+        self.6.clear();
+        self.show(page, Destination::Drop, Transition::None, false);
+    }
+
+    /// Goes back to the previous page. Returns `false` if there is no history.
+    pub fn pop(&mut self) -> bool {
+        self.finish();
+        match self.5.len() {
+            0 => false,
+            depth => self.pop_until(depth - 1)
+        }
+    }
+
+    /// Goes forward to the page that was last popped. Returns `false` if there is none.
+    pub fn forward(&mut self) -> bool {
+        self.finish();
+        let Some(next) = self.6.pop() else { return false; };
+        let transition = next.transition();
+        self.show(next, Destination::Back, transition, false);
+        true
+    }
+
+    /// Goes back to the first page in the history.
+    pub fn pop_to_root(&mut self) -> bool {
+        self.finish();
+        self.pop_until(0)
+    }
+
+    /// Goes back to the most recent page of type `id`. Does nothing if no such page is in the history.
+    pub fn pop_to(&mut self, id: TypeId) -> bool {
+        self.finish();
+        let Some(depth) = self.5.iter().rposition(|p| (**p).as_any().type_id() == id) else { return false; };
+        self.pop_until(depth)
+    }
+
+    /// Replaces the current page with the result of [`AppPage::navigate`].
+    pub fn navigate(&mut self, ctx: &mut Context, index: usize) {
+        self.finish();
+        let page = match self.2.take().unwrap().navigate(ctx, index) {
+            Ok(p) => p,
+            Err(e) => Box::new(Error::new(ctx, "404 Page Not Found", e))
+        };
+        let transition = page.transition();
+        self.show(page, Destination::Drop, transition, false);
+    }
+
+    /// Jumps to the end of the running transition.
+    pub fn finish(&mut self) {
+        *self.0.0.lock().unwrap() = Motion::default();
+        self.4.shape().color = Color(0, 0, 0, 0);
+        if let Some(page) = self.1.take().or_else(|| self.3.take()) {
+            match std::mem::replace(&mut self.7, Destination::Drop) {
+                Destination::Back => self.5.push(page),
+                Destination::Forward(i) => self.6.insert(i.min(self.6.len()), page),
+                Destination::Drop => {}
+            }
+        }
+    }
+
+    /// Shows the page at `depth` in the history, moving the pages above it to the forward stack.
+    fn pop_until(&mut self, depth: usize) -> bool {
+        if depth >= self.5.len() { return false; }
+        let insert = self.6.len();
+        let skipped = self.5.split_off(depth + 1);
+        let target = self.5.pop().unwrap();
+        self.6.extend(skipped.into_iter().rev());
+        let transition = self.2.as_ref().map(|p| p.transition()).unwrap_or_default();
+        self.show(target, Destination::Forward(insert), transition, true);
+        true
+    }
+
+    /// Makes `page` the current page, animating the old page out if there is a transition.
+    fn show(&mut self, page: Box<dyn AppPage>, destination: Destination, transition: Transition, reverse: bool) {
+        self.finish();
+        crate::config::clear_input_focus();
+        let previous = self.2.replace(page);
+        // Without an outgoing page there is nothing to animate against.
+        let transition = if previous.is_some() {transition.resolve()} else {Transition::None};
+        match reverse {
+            false => self.1 = previous,
+            true => self.3 = previous,
+        }
+        self.7 = destination;
+
+        match transition {
+            Transition::None => self.finish(),
+            transition => *self.0.0.lock().unwrap() = Motion { transition, reverse, start: Some(Instant::now()), progress: 0.0 },
+        }
+    }
+
+    fn tick(&mut self, ctx: &mut Context) {
+        let progress = {
+            let mut motion = self.0.0.lock().unwrap();
+            let Some(start) = motion.start else { return; };
+            motion.progress = (start.elapsed().as_secs_f32() / motion.transition.duration()).min(1.0);
+            if motion.transition == Transition::Fade {
+                let Color(r, g, b, _) = ctx.theme.colors.background.primary;
+                let alpha = 1.0 - (2.0 * motion.progress - 1.0).abs();
+                self.4.shape().color = Color(r, g, b, (alpha * 255.0) as u8);
+            }
+            motion.progress
+        };
+        if progress >= 1.0 { self.finish(); }
+    }
+}
+
+impl OnEvent for PageHost {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            self.tick(ctx);
+        } else if event.downcast_ref::<MouseEvent>().is_some() {
+            return !self.is_animating();
+        } else if self.8 && event.downcast_ref::<KeyboardEvent>().is_some() {
+            return false;
+        } else if let Some(NavigateEvent(index)) = event.downcast_ref::<NavigateEvent>() {
+            self.navigate(ctx, *index);
+        } else if let Some(navigation) = event.downcast_ref::<NavigationEvent>() {
+            match navigation {
+                NavigationEvent::Push(page) => if let Some(page) = page.take() { self.push(page) },
+                NavigationEvent::Replace(page) => if let Some(page) = page.take() { self.replace(page) },
+                NavigationEvent::Pop => {self.pop();},
+                NavigationEvent::Forward => {self.forward();},
+                NavigationEvent::PopToRoot => {self.pop_to_root();},
+                NavigationEvent::PopTo(id) => {self.pop_to(*id);},
+            }
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(NamedKey::Escape | NamedKey::BrowserBack | NamedKey::GoBack)}) = event.downcast_ref::<KeyboardEvent>() {
+            // A focused input takes Escape to leave the input instead.
+            let allowed = self.2.as_ref().is_none_or(|page| page.can_go_back());
+            if allowed && !crate::config::input_focused() { self.pop(); }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for PageHost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PageHost({})", self.5.len())
+    }
+}
+
+/// Where the outgoing page goes once its transition has finished.
+#[derive(Debug)]
+enum Destination {
+    Back,
+    Forward(usize),
+    Drop,
+}
+
+#[derive(Debug, Default)]
+struct Motion {
+    transition: Transition,
+    reverse: bool,
+    start: Option<Instant>,
+    progress: f32,
+}
+
+/// Stacks the pages of a [`PageHost`], offsetting them by the progress of the running transition.
+///
+/// The children are the outgoing page when it moves under the current page, the current page,
+/// the outgoing page when it moves over the current page, and the fade scrim.
+#[derive(Debug, Default)]
+struct HostLayout(Arc<Mutex<Motion>>);
+
+impl Layout for HostLayout {
+    fn request_size(&self, ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        Stack::default().request_size(ctx, children)
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let motion = self.0.lock().unwrap();
+        let p = 1.0 - (1.0 - motion.progress).powi(3);
+        let animating = motion.start.is_some();
+
+        // Offsets of the page that is leaving and the page that is arriving.
+        let (leaving, arriving) = match (motion.transition, motion.reverse) {
+            _ if !animating => ((0.0, 0.0), (0.0, 0.0)),
+            (Transition::Slide, false) => ((-size.0 * 0.3 * p, 0.0), (size.0 * (1.0 - p), 0.0)),
+            (Transition::Slide, true) => ((size.0 * p, 0.0), (-size.0 * 0.3 * (1.0 - p), 0.0)),
+            (Transition::Modal, false) => ((0.0, 0.0), (0.0, size.1 * (1.0 - p))),
+            (Transition::Modal, true) => ((0.0, size.1 * p), (0.0, 0.0)),
+            // The hidden page is moved out of the window while the scrim covers the swap.
+            (Transition::Fade, _) if motion.progress < 0.5 => ((0.0, 0.0), (size.0 * 2.0, 0.0)),
+            (Transition::Fade, _) => ((size.0 * 2.0, 0.0), (0.0, 0.0)),
+            (Transition::None, _) => ((0.0, 0.0), (0.0, 0.0)),
+        };
+
+        let place = |request: &SizeRequest, offset: (f32, f32)| {
+            let page = request.get(size);
+            Area{offset: (Offset::Center.get(size.0, page.0) + offset.0, Offset::Center.get(size.1, page.1) + offset.1), size: page}
+        };
+
+        let scrim = match animating && motion.transition == Transition::Fade {
+            true => Area{offset: (0.0, 0.0), size},
+            false => Area{offset: (0.0, 0.0), size: (0.0, 0.0)},
+        };
+
+        let pages = &children[..children.len()-1];
+        let mut areas = match (pages.len(), motion.reverse) {
+            (2, false) => vec![place(&pages[0], leaving), place(&pages[1], arriving)],
+            (2, true) => vec![place(&pages[0], arriving), place(&pages[1], leaving)],
+            _ => pages.iter().map(|r| place(r, arriving)).collect(),
+        };
+        areas.push(scrim);
+        areas
+    }
+}
+
+/// # Navigator Tabs
+///
+/// The pages behind the tabs of an interface's navigator.
+///
+/// Each tab keeps its own [`PageHost`], so switching away from a tab and back shows the page,
+/// scroll position and history exactly as they were left. Tabs can be set to reset instead,
+/// in which case they are rebuilt from their builder every time they are selected.
+///
+/// Selecting the tab that is already showing goes back to its first page.
+///
+/// ```rust
+/// if let Some(tabs) = interface.tabs() {
+///     tabs.set_reset(2, true);
+/// }
+/// ```
+pub struct NavigatorTabs {
+    builders: Vec<Box<dyn FnMut(&mut Context) -> Box<dyn AppPage>>>,
+    saved: Vec<Option<PageHost>>,
+    reset: Vec<bool>,
+    current: usize,
+}
+
+impl NavigatorTabs {
+    /// Takes the page builders out of the navigation of an interface.
+    pub fn new(navigation: &mut (usize, Vec<NavigateInfo>, Vec<NavigateInfo>)) -> Self {
+        let builders = navigation.1.iter_mut().chain(navigation.2.iter_mut()).map(|n| n.3.take().unwrap()).collect::<Vec<_>>();
+        let count = builders.len();
+        NavigatorTabs {
+            builders,
+            saved: (0..count).map(|_| None).collect(),
+            reset: vec![false; count],
+            current: navigation.0,
+        }
+    }
+
+    /// Returns the index of the selected tab.
+    pub fn current(&self) -> usize { self.current }
+    /// Returns the number of tabs.
+    pub fn len(&self) -> usize { self.builders.len() }
+    /// Returns `true` if there are no tabs.
+    pub fn is_empty(&self) -> bool { self.builders.is_empty() }
+    /// Returns whether the tab at `index` is rebuilt every time it is selected.
+    pub fn resets(&self, index: usize) -> bool { self.reset.get(index).copied().unwrap_or(false) }
+
+    /// Sets whether the tab at `index` is rebuilt every time it is selected.
+    pub fn set_reset(&mut self, index: usize, reset: bool) {
+        if let Some(r) = self.reset.get_mut(index) { *r = reset; }
+        if reset { if let Some(saved) = self.saved.get_mut(index) { *saved = None; } }
+    }
+
+    /// Drops the saved pages of the tab at `index` so it is rebuilt the next time it is selected.
+    pub fn clear(&mut self, index: usize) {
+        if let Some(saved) = self.saved.get_mut(index) { *saved = None; }
+    }
+
+    /// Switches `host` to the tab at `index`, saving the pages of the tab that was showing.
+    pub fn select(&mut self, ctx: &mut Context, index: usize, host: &mut PageHost) {
+        if index >= self.builders.len() { return; }
+        host.finish();
+        crate::config::clear_input_focus();
+
+        if index == self.current {
+            match self.reset[index] {
+                true => host.reset((self.builders[index])(ctx)),
+                false => {host.pop_to_root();}
+            }
+            return;
+        }
+
+        let next = match self.saved[index].take() {
+            Some(saved) if !self.reset[index] => saved,
+            _ => PageHost::new((self.builders[index])(ctx)),
+        };
+        let previous = std::mem::replace(host, next);
+        if !self.reset[self.current] { self.saved[self.current] = Some(previous); }
+        self.current = index;
+    }
+}
+
+impl std::fmt::Debug for NavigatorTabs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NavigatorTabs({}/{})", self.current, self.builders.len())
+    }
+}
@@ -4,62 +4,75 @@ use pelican_ui::{
     OnEvent, SizeRequest,
 };
 
-use crate::events::{KeyboardActiveEvent, NavigatorSelect, NavigateEvent, NavigatorEvent};
+use pelican_ui::TickEvent;
+
+use crate::events::{KeyboardActiveEvent, NavigatorSelect, NavigateEvent, NavigationEvent, NavigatorEvent};
 use crate::layout::{Column, Row, Padding, Offset, Size, Opt, Stack, Bin};
 use crate::components::{IconButton, ButtonState};
 use crate::elements::Rectangle;
 use crate::utils::ElementID;
 use crate::pages::AppPage;
-use crate::pages::Error;
 
 use std::fmt::Debug;
-use super::{NavigationButton, NavigateInfo, MobileKeyboard, PageBuilder};
+use super::{NavigationButton, NavigateInfo, MobileKeyboard, NavigatorTabs, PageHost};
 
 #[derive(Component)]
-pub struct MobileInterface(Column, Bin<Stack, Rectangle>, Option<Box<dyn AppPage>>, Option<MobileKeyboard>, Option<Opt<MobileNavigator>>, Bin<Stack, Rectangle>,  #[skip] PageBuilder);
+pub struct MobileInterface(Column, Bin<Stack, Rectangle>, PageHost, Option<MobileKeyboard>, Option<Opt<MobileNavigator>>, Bin<Stack, Rectangle>,  #[skip] Option<NavigatorTabs>);
 
 impl MobileInterface {
     pub fn new(
         ctx: &mut Context, 
         start_page: Box<dyn AppPage>,
         mut navigation: Option<(usize, Vec<NavigateInfo>, Vec<NavigateInfo>)>
+    ) -> Self {
+        let tabs = navigation.as_mut().map(NavigatorTabs::new);
+        Self::with_host(ctx, PageHost::new(start_page), tabs, navigation)
+    }
+
+    pub(crate) fn with_host(
+        ctx: &mut Context, 
+        host: PageHost,
+        tabs: Option<NavigatorTabs>,
+        navigation: Option<(usize, Vec<NavigateInfo>, Vec<NavigateInfo>)>
     ) -> Self {
         let background = ctx.theme.colors.background.primary;
-        let pages = navigation.as_mut().map(|nav| nav.1.iter_mut().map(|n| n.3.take().unwrap()).collect::<Vec<_>>());
-        let navigator = navigation.map(|n| Opt::new(MobileNavigator::new(ctx, n), true));
+        let has_nav = host.has_nav();
+        let navigator = navigation.map(|n| Opt::new(MobileNavigator::new(ctx, n), has_nav));
         let insets = ctx.hardware.safe_area_insets();
         let inset = |h: f32| Bin(Stack(Offset::Center, Offset::Center, Size::fill(), Size::Static(h), Padding::default()), Rectangle::new(background, 0.0));
         MobileInterface(
             Column::new(0.0, Offset::Center, Size::Fit, Padding::default()), 
             inset(insets.0),
-            Some(start_page), 
+            host, 
             None, 
             navigator,
             inset(insets.1),
-            pages
+            tabs
         )
     }
 
-    pub fn page(&mut self) -> &mut Option<Box<dyn AppPage>> { &mut self.2 }
+    pub fn page(&mut self) -> &mut Option<Box<dyn AppPage>> { self.2.page() }
+    pub fn host(&mut self) -> &mut PageHost { &mut self.2 }
+    pub fn tabs(&mut self) -> Option<&mut NavigatorTabs> { self.6.as_mut() }
     pub fn navigator(&mut self) -> &mut Option<Opt<MobileNavigator>> { &mut self.4 }
+
+    /// Consumes the interface, returning its pages and the navigator's tabs.
+    pub fn into_parts(self) -> (PageHost, Option<NavigatorTabs>) { (self.2, self.6) }
 }
 
 impl OnEvent for MobileInterface {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
-        if let Some(NavigateEvent(index)) = event.downcast_mut::<NavigateEvent>() {
+        if event.downcast_ref::<NavigateEvent>().is_some() || event.downcast_ref::<NavigationEvent>().is_some() {
             self.3 = None;
-            self.2 = match self.2.take().unwrap().navigate(ctx, *index) {
-                Ok(p) => Some(p),
-                Err(e) => Some(Box::new(Error::new(ctx, "404 Page Not Found", e)))
-            };
-
-            if let Some(navigator) = &mut self.4 {navigator.display(self.2.as_ref().map(|s| s.has_nav()).unwrap_or(false));}
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            let has_nav = self.2.has_nav();
+            if let Some(navigator) = &mut self.4 {navigator.display(has_nav);}
         } else if let Some(NavigatorEvent(index)) = event.downcast_mut::<NavigatorEvent>() {
             self.3 = None;
-            if let Some(nav) = self.6.as_mut() { self.2 = Some(nav[*index](ctx)); }
+            if let Some(tabs) = self.6.as_mut() { tabs.select(ctx, *index, &mut self.2); }
         } else if let Some(KeyboardActiveEvent(keyboard)) = event.downcast_ref::<KeyboardActiveEvent>() {
             match keyboard {
-                Some(_) if self.3.is_some() => {},
+                Some(_) if self.3.is_some() || !crate::config::IS_MOBILE => {},
                 Some(a) => self.3 = Some(MobileKeyboard::new(ctx, *a)),
                 None => self.3 = None
             }
@@ -1,5 +1,15 @@
 pub mod general;
 pub use general::*;
+pub mod host;
+pub use host::*;
+pub mod modal;
+pub use modal::*;
+pub mod popover;
+pub use popover::*;
+pub mod tooltip;
+pub use tooltip::*;
+pub mod toast;
+pub use toast::*;
 pub mod mobile;
 pub use mobile::*;
 pub mod desktop;
@@ -0,0 +1,366 @@
+use pelican_ui::{
+    Align, Area, Color, Component, Context,
+    Drawable, Event, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+};
+
+use crate::elements::{Rectangle, RoundedRectangle, Text, ExpandableText, TextStyle};
+use crate::events::{DismissModalEvent, PresentModalEvent};
+use crate::layout::{Bin, Column, Row, Offset, Padding, Size, Stack};
+use crate::components::{Button, ButtonSize, ButtonState, ButtonStyle, ButtonWidth};
+
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+type ResultCallback = Box<dyn FnMut(&mut Context, ModalResult)>;
+
+/// How a [`Modal`] was dismissed. Passed to the modal's result callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModalResult {
+    /// The primary button was pressed.
+    Primary,
+    /// The secondary button was pressed.
+    Secondary,
+    /// The modal was closed without a choice, by the scrim, a drag or the Escape key.
+    Dismissed,
+    /// A custom result sent through a [`DismissModalEvent`].
+    Custom(usize),
+}
+
+/// How a [`Modal`] is presented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModalStyle {
+    /// A card centered over the page.
+    Dialog,
+    /// A card attached to the bottom of the window that can be dragged down to dismiss.
+    Sheet,
+    /// Content covering the whole window, sliding up from the bottom.
+    FullScreen,
+}
+
+/// # Modal
+///
+/// Content presented over the current page with a [`PresentModalEvent`].
+///
+/// A scrim covers the page underneath and blocks its input. Pressing the scrim dismisses
+/// dialogs and sheets unless [`set_dismissible`](Modal::set_dismissible) turned it off.
+///
+/// The modal is closed with a [`DismissModalEvent`], which passes its [`ModalResult`] to the
+/// callback given when the modal was created.
+///
+/// ```rust
+/// let modal = Modal::dialog(ctx, "Send 0.01 BTC?", "This payment can't be reversed.", Some("Send"), Some("Cancel"), |ctx: &mut Context, result: ModalResult| {
+///     if result == ModalResult::Primary { ctx.trigger_event(NavigateEvent(2)); }
+/// });
+/// ctx.trigger_event(PresentModalEvent::new(modal));
+/// ```
+#[derive(Component)]
+pub struct Modal(ModalLayout, Rectangle, Box<dyn Drawable>, #[skip] Option<ResultCallback>, #[skip] Option<ModalResult>, #[skip] bool);
+
+impl Modal {
+    pub const DURATION: f32 = 0.25;
+
+    /// Creates a modal from any content.
+    pub fn new(style: ModalStyle, content: Box<dyn Drawable>, on_result: impl FnMut(&mut Context, ModalResult) + 'static) -> Self {
+        let layout = ModalLayout(Arc::new(Mutex::new(ModalState::new(style))));
+        Modal(layout, Rectangle::new(Color(0, 0, 0, 0), 0.0), content, Some(Box::new(on_result)), None, style != ModalStyle::FullScreen)
+    }
+
+    /// A dialog with a title, a body and up to two buttons.
+    pub fn dialog(
+        ctx: &mut Context,
+        title: &str,
+        body: &str,
+        primary: Option<&str>,
+        secondary: Option<&str>,
+        on_result: impl FnMut(&mut Context, ModalResult) + 'static,
+    ) -> Self {
+        let content = DialogContent::new(ctx, title, body, primary, secondary);
+        Modal::new(ModalStyle::Dialog, Box::new(ModalCard::new(ctx, Box::new(content))), on_result)
+    }
+
+    /// A bottom sheet holding a column of content.
+    pub fn sheet(
+        ctx: &mut Context,
+        content: Vec<Box<dyn Drawable>>,
+        on_result: impl FnMut(&mut Context, ModalResult) + 'static,
+    ) -> Self {
+        let content = SheetContent::new(ctx, content);
+        Modal::new(ModalStyle::Sheet, Box::new(ModalCard::new(ctx, Box::new(content))), on_result)
+    }
+
+    /// A full-screen modal, usually holding a [`Page`](crate::Page) with a close button in its header.
+    pub fn full_screen(
+        content: Box<dyn Drawable>,
+        on_result: impl FnMut(&mut Context, ModalResult) + 'static,
+    ) -> Self {
+        Modal::new(ModalStyle::FullScreen, content, on_result)
+    }
+
+    /// Returns how the modal is presented.
+    pub fn style(&self) -> ModalStyle { self.0.0.lock().unwrap().style }
+    /// Returns the content of the modal.
+    pub fn content(&mut self) -> &mut Box<dyn Drawable> { &mut self.2 }
+    /// Sets whether pressing the scrim or dragging the sheet dismisses the modal.
+    pub fn set_dismissible(&mut self, dismissible: bool) { self.5 = dismissible; }
+    /// Returns `true` once the modal has been dismissed.
+    pub fn is_closing(&self) -> bool { self.4.is_some() }
+
+    /// Starts closing the modal with `result`.
+    pub fn close(&mut self, result: ModalResult) {
+        if self.4.is_some() { return; }
+        self.4 = Some(result);
+        let mut state = self.0.0.lock().unwrap();
+        state.start = Instant::now();
+        state.from = state.progress;
+    }
+
+    /// Returns `true` once the closing animation has finished.
+    fn is_closed(&self) -> bool {
+        self.4.is_some() && self.0.0.lock().unwrap().progress <= 0.0
+    }
+
+    /// Passes the result to the callback.
+    fn finish(&mut self, ctx: &mut Context) {
+        if let (Some(mut callback), Some(result)) = (self.3.take(), self.4) { callback(ctx, result); }
+    }
+}
+
+impl OnEvent for Modal {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<TickEvent>().is_some() {
+            let mut state = self.0.0.lock().unwrap();
+            let duration = if crate::config::reduce_motion() {0.0} else {Self::DURATION};
+            let t = match duration > 0.0 {
+                true => (state.start.elapsed().as_secs_f32() / duration).min(1.0),
+                false => 1.0
+            };
+            state.progress = match self.4.is_some() {
+                true => state.from * (1.0 - t),
+                false => state.from + (1.0 - state.from) * t,
+            };
+            if state.drag.is_none() && state.offset > 0.0 { state.offset = (state.offset * 0.7 - 1.0).max(0.0); }
+            self.1.shape().color = Color(0, 0, 0, (state.progress * 102.0) as u8);
+        } else if let Some(MouseEvent{state: mouse, position}) = event.downcast_ref::<MouseEvent>() {
+            if self.4.is_some() { return false; }
+            let mut state = self.0.0.lock().unwrap();
+            let inside = position.map(|p| state.contains(p));
+            match (mouse, position, inside) {
+                (MouseState::Pressed, Some(p), Some(true)) if state.style == ModalStyle::Sheet && self.5 => state.drag = Some(p.1 - state.offset),
+                (MouseState::Pressed, Some(_), Some(false)) if self.5 => {
+                    drop(state);
+                    self.close(ModalResult::Dismissed);
+                    return false;
+                },
+                (MouseState::Moved, Some(p), _) => if let Some(start) = state.drag { state.offset = (p.1 - start).max(0.0) },
+                (MouseState::Released, _, _) => if state.drag.take().is_some() && state.offset > state.card.1.1 * 0.3 {
+                    drop(state);
+                    self.close(ModalResult::Dismissed);
+                    return false;
+                },
+                _ => {}
+            }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for Modal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Modal({:?})", self.style())
+    }
+}
+
+#[derive(Debug)]
+struct ModalState {
+    style: ModalStyle,
+    start: Instant,
+    from: f32,
+    progress: f32,
+    drag: Option<f32>,
+    offset: f32,
+    card: ((f32, f32), (f32, f32)),
+}
+
+impl ModalState {
+    fn new(style: ModalStyle) -> Self {
+        ModalState { style, start: Instant::now(), from: 0.0, progress: 0.0, drag: None, offset: 0.0, card: ((0.0, 0.0), (0.0, 0.0)) }
+    }
+
+    fn contains(&self, position: (f32, f32)) -> bool {
+        let ((x, y), (w, h)) = self.card;
+        position.0 >= x && position.0 <= x + w && position.1 >= y && position.1 <= y + h
+    }
+}
+
+/// Places the scrim over the whole window and the content according to the [`ModalStyle`].
+#[derive(Debug)]
+struct ModalLayout(Arc<Mutex<ModalState>>);
+
+impl ModalLayout {
+    const MARGIN: f32 = 24.0;
+    const DIALOG_MAX: f32 = 400.0;
+}
+
+impl Layout for ModalLayout {
+    fn request_size(&self, _ctx: &mut Context, _children: Vec<SizeRequest>) -> SizeRequest {
+        SizeRequest::fill()
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let mut state = self.0.lock().unwrap();
+        let p = 1.0 - (1.0 - state.progress).powi(3);
+        let content = &children[1];
+
+        let (offset, card) = match state.style {
+            ModalStyle::Dialog => {
+                let width = (size.0 - Self::MARGIN * 2.0).min(Self::DIALOG_MAX);
+                let card = content.get((width, content.min_height().min(size.1 - Self::MARGIN * 2.0)));
+                let offset = Offset::Center.get(size.1, card.1) + 16.0 * (1.0 - p);
+                ((Offset::Center.get(size.0, card.0), offset), card)
+            },
+            ModalStyle::Sheet => {
+                let width = size.0.min(crate::config::content_max(f32::MAX));
+                let card = content.get((width, content.min_height().min(size.1 * 0.9)));
+                let offset = size.1 - card.1 + card.1 * (1.0 - p) + state.offset;
+                ((Offset::Center.get(size.0, card.0), offset), card)
+            },
+            ModalStyle::FullScreen => ((0.0, size.1 * (1.0 - p)), content.get(size)),
+        };
+        state.card = (offset, card);
+
+        vec![Area{offset: (0.0, 0.0), size}, Area{offset, size: card}]
+    }
+}
+
+/// A rounded card on the background color holding the content of dialogs and sheets.
+#[derive(Debug, Component)]
+struct ModalCard(Stack, RoundedRectangle, Box<dyn Drawable>);
+impl OnEvent for ModalCard {}
+
+impl ModalCard {
+    fn new(ctx: &mut Context, content: Box<dyn Drawable>) -> Self {
+        let background = ctx.theme.colors.background.primary;
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[1].0, f32::MAX));
+        let height = Size::custom(move |heights: Vec<(f32, f32)>|heights[1]);
+        ModalCard(
+            Stack(Offset::Center, Offset::Start, width, height, Padding::default()),
+            RoundedRectangle::new(0.0, 16.0, background),
+            content
+        )
+    }
+}
+
+#[derive(Debug, Component)]
+struct DialogContent(Column, Text, ExpandableText, ModalButtons);
+impl OnEvent for DialogContent {}
+
+impl DialogContent {
+    fn new(ctx: &mut Context, title: &str, body: &str, primary: Option<&str>, secondary: Option<&str>) -> Self {
+        let font_size = ctx.theme.fonts.size;
+        let mut buttons = Vec::new();
+        if let Some(label) = secondary {
+            buttons.push(Button::new(
+                ctx, None, None, Some(label), None, ButtonSize::Large, ButtonWidth::Expand, ButtonStyle::Secondary,
+                ButtonState::Default, Offset::Center, |ctx: &mut Context| ctx.trigger_event(DismissModalEvent(ModalResult::Secondary)), None
+            ));
+        }
+        if let Some(label) = primary {
+            buttons.push(Button::primary(ctx, label, |ctx: &mut Context| ctx.trigger_event(DismissModalEvent(ModalResult::Primary))));
+        }
+
+        DialogContent(
+            Column::new(16.0, Offset::Start, Size::Fit, Padding::new(24.0)),
+            Text::new(ctx, title, TextStyle::Heading, font_size.h4, Align::Left),
+            ExpandableText::new(ctx, body, TextStyle::Primary, font_size.md, Align::Left, None),
+            ModalButtons(Row::new(12.0, Offset::Center, Size::Fit, Padding(0.0, 8.0, 0.0, 0.0)), buttons),
+        )
+    }
+}
+
+#[derive(Debug, Component)]
+struct ModalButtons(Row, Vec<Button>);
+impl OnEvent for ModalButtons {}
+
+/// A drag handle above a column of content.
+#[derive(Debug, Component)]
+struct SheetContent(Column, Bin<Stack, RoundedRectangle>, Vec<Box<dyn Drawable>>);
+impl OnEvent for SheetContent {}
+
+impl SheetContent {
+    fn new(ctx: &mut Context, content: Vec<Box<dyn Drawable>>) -> Self {
+        let color = ctx.theme.colors.outline.secondary;
+        let bottom = ctx.hardware.safe_area_insets().1 + 24.0;
+        SheetContent(
+            Column::new(16.0, Offset::Center, Size::Fit, Padding(24.0, 12.0, 24.0, bottom)),
+            Bin(
+                Stack(Offset::Center, Offset::Center, Size::Static(36.0), Size::Static(4.0), Padding::default()),
+                RoundedRectangle::new(0.0, 2.0, color)
+            ),
+            content
+        )
+    }
+}
+
+/// # Modal Layer
+///
+/// The layer of the [`Interface`](super::Interface) that presents [`Modal`]s over the current page.
+/// Modals are stacked in the order they are presented, and only the top-most one is dismissed by a
+/// [`DismissModalEvent`]. The layer takes no space while it is empty.
+#[derive(Debug, Component)]
+pub struct ModalLayer(OverlayLayout, Vec<Modal>);
+
+impl ModalLayer {
+    pub fn new() -> Self {
+        ModalLayer(OverlayLayout, Vec::new())
+    }
+
+    /// Returns the presented modals, bottom-most first.
+    pub fn modals(&mut self) -> &mut Vec<Modal> { &mut self.1 }
+
+    /// Returns `true` if a modal is presented and not closing.
+    pub fn is_active(&self) -> bool { self.1.iter().any(|m| !m.is_closing()) }
+
+    /// Dismisses the top-most modal that is not already closing.
+    pub fn dismiss(&mut self, result: ModalResult) {
+        if let Some(modal) = self.1.iter_mut().rev().find(|m| !m.is_closing()) { modal.close(result); }
+    }
+}
+
+impl Default for ModalLayer {
+    fn default() -> Self { Self::new() }
+}
+
+impl OnEvent for ModalLayer {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(PresentModalEvent(modal)) = event.downcast_ref::<PresentModalEvent>() {
+            if let Some(modal) = modal.take() { self.1.push(modal); }
+        } else if let Some(DismissModalEvent(result)) = event.downcast_ref::<DismissModalEvent>() {
+            self.dismiss(*result);
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            let (closed, open): (Vec<_>, Vec<_>) = std::mem::take(&mut self.1).into_iter().partition(|m| m.is_closed());
+            self.1 = open;
+            closed.into_iter().for_each(|mut m| m.finish(ctx));
+        }
+        true
+    }
+}
+
+/// Fills the window while it has children and takes no space otherwise,
+/// so an empty overlay doesn't catch the mouse.
+#[derive(Debug)]
+pub(crate) struct OverlayLayout;
+
+impl Layout for OverlayLayout {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        match children.is_empty() {
+            true => SizeRequest::new(0.0, 0.0, 0.0, 0.0),
+            false => SizeRequest::fill(),
+        }
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        children.into_iter().map(|_| Area{offset: (0.0, 0.0), size}).collect()
+    }
+}
@@ -0,0 +1,231 @@
+use pelican_ui::{
+    Area, Component, Context,
+    Drawable, Event, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+};
+
+use crate::elements::OutlinedRectangle;
+use crate::events::{AnchorEvent, DismissPopoverEvent, PresentPopoverEvent};
+use crate::layout::{Offset, Padding, Size, Stack};
+use crate::utils::ElementID;
+
+use std::sync::{Arc, Mutex};
+
+pub(crate) type Rect = ((f32, f32), (f32, f32));
+
+/// Where a [`Popover`] is placed next to its anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Placement {
+    /// Under the anchor, or above it when there is more room there.
+    #[default]
+    Below,
+    /// At a point relative to the anchor, like where a context menu was opened.
+    At((f32, f32)),
+}
+
+/// # Popover
+///
+/// A floating card anchored to the element with an [`ElementID`], shown with a [`PresentPopoverEvent`].
+/// The anchor reports where it is through an [`AnchorEvent`], and the popover follows it while it moves.
+///
+/// Popovers always stay inside the window. Pressing outside the popover, pressing Escape
+/// or triggering a [`DismissPopoverEvent`] hides it.
+///
+/// ```rust
+/// let popover = Popover::below(ctx, id, Box::new(menu)).match_width();
+/// ctx.trigger_event(PresentPopoverEvent::new(popover));
+/// ```
+#[derive(Debug, Component)]
+pub struct Popover(Stack, OutlinedRectangle, Box<dyn Drawable>, #[skip] ElementID, #[skip] Placement, #[skip] bool);
+impl OnEvent for Popover {}
+
+impl Popover {
+    pub fn new(ctx: &mut Context, anchor: ElementID, placement: Placement, content: Box<dyn Drawable>) -> Self {
+        let colors = &ctx.theme.colors;
+        let (background, outline) = (colors.background.primary, colors.outline.secondary);
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[1].0, f32::MAX));
+        let height = Size::custom(move |heights: Vec<(f32, f32)>|heights[1]);
+        Popover(
+            Stack(Offset::Start, Offset::Start, width, height, Padding::new(4.0)),
+            OutlinedRectangle::new(background, outline, 8.0, 1.0),
+            content,
+            anchor,
+            placement,
+            false
+        )
+    }
+
+    /// A popover under the element with the `anchor` id.
+    pub fn below(ctx: &mut Context, anchor: ElementID, content: Box<dyn Drawable>) -> Self {
+        Popover::new(ctx, anchor, Placement::Below, content)
+    }
+
+    /// A popover at `point`, relative to the element with the `anchor` id.
+    pub fn at(ctx: &mut Context, anchor: ElementID, point: (f32, f32), content: Box<dyn Drawable>) -> Self {
+        Popover::new(ctx, anchor, Placement::At(point), content)
+    }
+
+    /// Makes the popover at least as wide as its anchor.
+    pub fn match_width(mut self) -> Self {
+        self.5 = true;
+        self
+    }
+
+    /// Returns the content of the popover.
+    pub fn content(&mut self) -> &mut Box<dyn Drawable> { &mut self.2 }
+    /// Returns the id of the anchor.
+    pub fn anchor(&self) -> ElementID { self.3 }
+    /// Returns where the popover is placed next to its anchor.
+    pub fn placement(&self) -> Placement { self.4 }
+}
+
+/// # Popover Layer
+///
+/// The layer of the [`Interface`](super::Interface) that shows a [`Popover`] above the page and any modals.
+/// While a popover is shown the layer covers the window, so a press outside the popover only hides it.
+#[derive(Debug, Component)]
+pub struct PopoverLayer(PopoverLayout, Option<Popover>, #[skip] Option<Arc<Mutex<Option<Rect>>>>);
+
+impl PopoverLayer {
+    pub fn new() -> Self {
+        PopoverLayer(PopoverLayout(Arc::new(Mutex::new(PopoverState::default()))), None, None)
+    }
+
+    /// Returns the visible popover.
+    pub fn popover(&mut self) -> &mut Option<Popover> { &mut self.1 }
+
+    /// Returns `true` if a popover is shown.
+    pub fn is_active(&self) -> bool { self.1.is_some() }
+
+    /// Hides the visible popover.
+    pub fn dismiss(&mut self) {
+        self.1 = None;
+        self.2 = None;
+        *self.0.0.lock().unwrap() = PopoverState::default();
+    }
+
+    /// Asks the anchor of the visible popover where it is.
+    fn locate(&mut self, ctx: &mut Context) {
+        let Some(popover) = self.1.as_ref() else { return; };
+        let event = AnchorEvent::new(popover.anchor());
+        self.2 = Some(event.target());
+        ctx.trigger_event(event);
+    }
+}
+
+impl Default for PopoverLayer {
+    fn default() -> Self { Self::new() }
+}
+
+impl OnEvent for PopoverLayer {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(PresentPopoverEvent(popover)) = event.downcast_ref::<PresentPopoverEvent>() {
+            if let Some(popover) = popover.take() {
+                self.dismiss();
+                let mut state = self.0.0.lock().unwrap();
+                state.placement = popover.placement();
+                state.match_width = popover.5;
+                drop(state);
+                self.1 = Some(popover);
+                self.locate(ctx);
+            }
+        } else if event.downcast_ref::<DismissPopoverEvent>().is_some() {
+            self.dismiss();
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            // The anchor can move while the popover is open, e.g. when the page scrolls.
+            if let Some(rect) = self.2.as_ref().and_then(|target| *target.lock().unwrap()) {
+                self.0.0.lock().unwrap().anchor = Some(rect);
+            }
+            self.locate(ctx);
+        } else if let Some(MouseEvent{state: MouseState::Pressed, position: Some(position)}) = event.downcast_ref::<MouseEvent>() {
+            let area = self.0.0.lock().unwrap().area;
+            let inside = area.map(|((x, y), (w, h))| position.0 >= x && position.0 <= x + w && position.1 >= y && position.1 <= y + h);
+            if self.1.is_some() && !inside.unwrap_or(false) {
+                self.dismiss();
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Debug, Default)]
+struct PopoverState {
+    placement: Placement,
+    match_width: bool,
+    /// Where the anchor is in the window.
+    anchor: Option<Rect>,
+    /// Where the popover was placed by the last layout.
+    area: Option<Rect>,
+}
+
+/// Covers the window while a located popover is shown, and places the popover next to its anchor inside the window.
+#[derive(Debug)]
+struct PopoverLayout(Arc<Mutex<PopoverState>>);
+
+impl PopoverLayout {
+    const GAP: f32 = 4.0;
+    const MARGIN: f32 = 8.0;
+}
+
+impl Layout for PopoverLayout {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        match !children.is_empty() && self.0.lock().unwrap().anchor.is_some() {
+            true => SizeRequest::fill(),
+            false => SizeRequest::new(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let mut state = self.0.lock().unwrap();
+        let Some(((ax, ay), (aw, ah))) = state.anchor else {
+            return children.into_iter().map(|_| Area{offset: (0.0, 0.0), size: (0.0, 0.0)}).collect();
+        };
+
+        let margin = Self::MARGIN;
+        children.into_iter().map(|request| {
+            let min = if state.match_width {request.min_width().max(aw)} else {request.min_width()};
+            let w = min.min(size.0 - (margin * 2.0)).max(0.0);
+            let h = request.min_height();
+
+            let ((x, y), (w, h)) = match state.placement {
+                Placement::Below => place_below(size, ((ax, ay), (aw, ah)), (w, h), false),
+                Placement::At((px, py)) => {
+                    let (px, py) = (ax + px, ay + py);
+                    let h = h.min(size.1 - (margin * 2.0)).max(0.0);
+                    let x = if px + w > size.0 - margin {px - w} else {px};
+                    let y = if py + h > size.1 - margin {py - h} else {py};
+                    clamp(size, ((x, y), (w, h)))
+                }
+            };
+
+            state.area = Some(((x, y), (w, h)));
+            Area{offset: (x, y), size: (w, h)}
+        }).collect()
+    }
+}
+
+/// Places a box of `size` under `anchor`, or above it when there is more room there, inside a `window`.
+/// The box is shortened to the room it has, and lined up with the start or the center of the anchor.
+pub(crate) fn place_below(window: (f32, f32), anchor: Rect, size: (f32, f32), centered: bool) -> Rect {
+    let (((ax, ay), (aw, ah)), (w, h)) = (anchor, size);
+    let (gap, margin) = (PopoverLayout::GAP, PopoverLayout::MARGIN);
+    let below = window.1 - (ay + ah + gap) - margin;
+    let above = ay - gap - margin;
+    let x = if centered {ax + ((aw - w) / 2.0)} else {ax};
+    let (y, h) = match h <= below || below >= above {
+        true => (ay + ah + gap, h.min(below.max(0.0))),
+        false => {
+            let h = h.min(above.max(0.0));
+            (ay - gap - h, h)
+        }
+    };
+    clamp(window, ((x, y), (w, h)))
+}
+
+/// Moves `rect` inside the margins of the `window`.
+fn clamp(window: (f32, f32), rect: Rect) -> Rect {
+    let (((x, y), (w, h)), margin) = (rect, PopoverLayout::MARGIN);
+    ((x.min(window.0 - margin - w).max(margin), y.min(window.1 - margin - h).max(margin)), (w, h))
+}
@@ -0,0 +1,193 @@
+use pelican_ui::{
+    Align, Area, Component, Context,
+    Drawable, Event, Image, Layout,
+    OnEvent, SizeRequest, TickEvent,
+};
+
+use crate::elements::{Icon, OutlinedRectangle, ExpandableText, TextStyle};
+use crate::events::{ToastEvent, DismissToastEvent};
+use crate::layout::{Offset, Padding, Row, Size, Stack};
+use crate::components::{AlertStyle, Button};
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, mpsc::{self, Receiver}};
+use std::time::Instant;
+
+/// # Toast
+///
+/// A short notification shown above the page with a [`ToastEvent`], like "Address copied".
+///
+/// Toasts are queued and shown one at a time. Each one is hidden after its duration, when its action
+/// is pressed, or with a [`DismissToastEvent`].
+///
+/// ```rust
+/// let toast = Toast::new(ctx, "Payment received", AlertStyle::Success)
+///     .action(ctx, "View", |ctx: &mut Context| ctx.trigger_event(NavigateEvent(1)));
+/// ctx.trigger_event(ToastEvent::new(toast));
+/// ```
+#[derive(Component)]
+pub struct Toast(Stack, OutlinedRectangle, ToastContent, #[skip] f32, #[skip] Option<Receiver<()>>);
+
+impl Toast {
+    pub const DURATION: f32 = 4.0;
+
+    pub fn new(ctx: &mut Context, message: &str, style: AlertStyle) -> Self {
+        let colors = &ctx.theme.colors;
+        let (background, outline) = (colors.background.secondary, colors.outline.secondary);
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[1].0, f32::MAX));
+        let height = Size::custom(move |heights: Vec<(f32, f32)>|heights[1]);
+        Toast(
+            Stack(Offset::Center, Offset::Start, width, height, Padding::default()),
+            OutlinedRectangle::new(background, outline, 12.0, 1.0),
+            ToastContent::new(ctx, message, style),
+            Self::DURATION,
+            None
+        )
+    }
+
+    /// Adds an action button. Pressing it runs `on_click` and hides the toast.
+    pub fn action(mut self, ctx: &mut Context, label: &str, mut on_click: impl FnMut(&mut Context) + 'static) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let button = Button::ghost(ctx, label, move |ctx: &mut Context| {
+            on_click(ctx);
+            let _ = sender.send(());
+        });
+        self.2.3 = Some(button);
+        self.4 = Some(receiver);
+        self
+    }
+
+    /// Sets how many seconds the toast is shown for.
+    pub fn duration(mut self, seconds: f32) -> Self {
+        self.3 = seconds;
+        self
+    }
+
+    /// Returns `true` if the action button was pressed since the last call.
+    fn acted(&mut self) -> bool {
+        self.4.as_ref().map(|r| r.try_recv().is_ok()).unwrap_or(false)
+    }
+}
+
+impl OnEvent for Toast {}
+
+impl std::fmt::Debug for Toast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Toast")
+    }
+}
+
+#[derive(Debug, Component)]
+struct ToastContent(Row, Image, ExpandableText, Option<Button>);
+impl OnEvent for ToastContent {}
+
+impl ToastContent {
+    fn new(ctx: &mut Context, message: &str, style: AlertStyle) -> Self {
+        let color = style.color(ctx);
+        let font_size = ctx.theme.fonts.size.md;
+        ToastContent(
+            Row::new(12.0, Offset::Center, Size::Fit, Padding(16.0, 12.0, 12.0, 12.0)),
+            Icon::new(ctx, style.icon(), color, 24.0),
+            ExpandableText::new(ctx, message, TextStyle::Primary, font_size, Align::Left, Some(3)),
+            None
+        )
+    }
+}
+
+/// # Toast Layer
+///
+/// The layer of the [`Interface`](super::Interface) that shows [`Toast`]s above the page.
+/// Toasts sit at the top of the window on mobile and at the bottom elsewhere.
+/// The layer only takes the space of the visible toast, so the page stays interactive around it.
+#[derive(Debug, Component)]
+pub struct ToastLayer(ToastLayout, Option<Toast>, #[skip] VecDeque<Toast>, #[skip] Option<(Instant, bool)>);
+
+impl ToastLayer {
+    pub const ANIMATION: f32 = 0.2;
+
+    pub fn new() -> Self {
+        ToastLayer(ToastLayout(Arc::new(Mutex::new(0.0))), None, VecDeque::new(), None)
+    }
+
+    /// Returns the toasts waiting to be shown.
+    pub fn queue(&mut self) -> &mut VecDeque<Toast> { &mut self.2 }
+
+    /// Starts hiding the visible toast.
+    pub fn dismiss(&mut self) {
+        if self.1.is_some() { self.3 = Some((Instant::now(), true)); }
+    }
+
+    fn tick(&mut self) {
+        if self.1.is_none() {
+            self.1 = self.2.pop_front();
+            self.3 = self.1.as_ref().map(|_| (Instant::now(), false));
+        }
+
+        let Some(toast) = self.1.as_mut() else { return; };
+        if toast.acted() { self.3 = Some((Instant::now(), true)); }
+
+        let Some((start, hiding)) = self.3 else { return; };
+        let animation = if crate::config::reduce_motion() {0.0} else {Self::ANIMATION};
+        let elapsed = start.elapsed().as_secs_f32();
+        let t = if animation > 0.0 {(elapsed / animation).min(1.0)} else {1.0};
+
+        *self.0.0.lock().unwrap() = if hiding {1.0 - t} else {t};
+        if hiding && t >= 1.0 {
+            self.1 = None;
+            self.3 = None;
+        } else if !hiding && elapsed >= animation + toast.3 {
+            self.3 = Some((Instant::now(), true));
+        }
+    }
+}
+
+impl Default for ToastLayer {
+    fn default() -> Self { Self::new() }
+}
+
+impl OnEvent for ToastLayer {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(ToastEvent(toast)) = event.downcast_ref::<ToastEvent>() {
+            if let Some(toast) = toast.take() { self.2.push_back(toast); }
+        } else if event.downcast_ref::<DismissToastEvent>().is_some() {
+            self.dismiss();
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            self.tick();
+        }
+        true
+    }
+}
+
+/// Slides the toast in from the edge of the layer's area as its progress goes from 0 to 1.
+#[derive(Debug)]
+struct ToastLayout(Arc<Mutex<f32>>);
+
+impl Layout for ToastLayout {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        match children.first() {
+            Some(toast) => SizeRequest::new(toast.min_width(), toast.min_height(), toast.max_width(), toast.min_height()),
+            None => SizeRequest::new(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    fn build(&self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let p = *self.0.lock().unwrap();
+        let p = 1.0 - (1.0 - p).powi(3);
+        let top = crate::config::interface_mode() == crate::config::InterfaceMode::Mobile;
+        let hidden = if top {-size.1} else {size.1};
+        children.into_iter().map(|toast| Area{offset: (0.0, hidden * (1.0 - p)), size: toast.get(size)}).collect()
+    }
+}
+
+/// Where the [`Interface`](super::Interface) places its [`ToastLayer`] inside a window of `size`.
+pub(crate) fn toast_area(ctx: &mut Context, size: (f32, f32), request: &SizeRequest) -> Area {
+    let insets = ctx.hardware.safe_area_insets();
+    let width = (size.0 - 32.0).min(480.0).max(0.0);
+    let toast = request.get((width, request.min_height()));
+    let x = Offset::Center.get(size.0, toast.0);
+    let y = match crate::config::interface_mode() {
+        crate::config::InterfaceMode::Mobile => insets.0 + 16.0,
+        _ => size.1 - toast.1 - 24.0,
+    };
+    Area{offset: (x, y), size: toast}
+}
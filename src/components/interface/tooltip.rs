@@ -0,0 +1,187 @@
+use pelican_ui::{
+    Align, Area, Component, Context,
+    Drawable, Event, Layout,
+    MouseEvent, MouseState, OnEvent,
+    SizeRequest, TickEvent,
+};
+
+use crate::elements::{OutlinedRectangle, Text, TextStyle};
+use crate::events::{AnchorEvent, HideTooltipEvent, ShowTooltipEvent};
+use crate::layout::{Offset, Padding, Size, Stack};
+use crate::components::ButtonState;
+use crate::utils::ElementID;
+
+use super::popover::{place_below, Rect};
+
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// # Tooltip
+///
+/// Gives any component a short hint, like "Copy address" on an icon button.
+///
+/// On desktop and web the hint is shown after the pointer rests on the component for [`Tooltip::DELAY`] seconds.
+/// On mobile it is shown while the component is long-pressed, and lifting the finger then doesn't press the component.
+/// The hint sits under the component, or above it when there is more room there, and always stays inside the window.
+///
+/// ```rust
+/// let copy = IconButton::secondary(ctx, "copy", |ctx: &mut Context| println!("Copied"));
+/// let copy = Tooltip::new(copy, "Copy address");
+/// ```
+#[derive(Debug, Component)]
+pub struct Tooltip(Stack, Box<dyn Drawable>, #[skip] ElementID, #[skip] String, #[skip] ButtonState, #[skip] Option<Instant>, #[skip] bool);
+
+impl Tooltip {
+    pub const DELAY: f32 = 0.6;
+    pub const LONG_PRESS: f32 = 0.5;
+
+    pub fn new(content: impl Drawable + 'static, text: &str) -> Self {
+        Tooltip(Stack::default(), Box::new(content), ElementID::new(), text.to_string(), ButtonState::Default, None, false)
+    }
+
+    /// Returns the component the tooltip is attached to.
+    pub fn content(&mut self) -> &mut Box<dyn Drawable> { &mut self.1 }
+    /// Returns the text of the tooltip.
+    pub fn text(&self) -> &str { &self.3 }
+
+    /// Changes the text of the tooltip, updating it if it is shown.
+    pub fn set_text(&mut self, ctx: &mut Context, text: &str) {
+        self.3 = text.to_string();
+        if self.6 { self.show(ctx); }
+    }
+
+    fn show(&mut self, ctx: &mut Context) {
+        self.6 = true;
+        ctx.trigger_event(ShowTooltipEvent{anchor: self.2, text: self.3.clone()});
+    }
+
+    fn hide(&mut self, ctx: &mut Context) {
+        self.5 = None;
+        if self.6 {
+            self.6 = false;
+            ctx.trigger_event(HideTooltipEvent(self.2));
+        }
+    }
+}
+
+impl OnEvent for Tooltip {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(anchor) = event.downcast_ref::<AnchorEvent>() {
+            anchor.report(self.2);
+        } else if let Some(event) = event.downcast_mut::<MouseEvent>() {
+            if crate::config::IS_MOBILE && self.6 && event.state == MouseState::Released {
+                // The long-press showed the tooltip, so lifting the finger doesn't also press the component.
+                *event = MouseEvent{state: MouseState::Moved, position: None};
+            }
+            match self.4.handle(ctx, *event) {
+                Some(ButtonState::Hover) if !crate::config::IS_MOBILE => self.5 = Some(Instant::now()),
+                Some(ButtonState::Pressed) if crate::config::IS_MOBILE => self.5 = Some(Instant::now()),
+                Some(_) => self.hide(ctx),
+                None => {}
+            }
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            let delay = if crate::config::IS_MOBILE {Self::LONG_PRESS} else {Self::DELAY};
+            if self.5.is_some_and(|start| start.elapsed().as_secs_f32() >= delay) {
+                self.5 = None;
+                if crate::config::IS_MOBILE { ctx.hardware.haptic(); }
+                self.show(ctx);
+            }
+        }
+        true
+    }
+}
+
+/// # Tooltip Layer
+///
+/// The layer of the [`Interface`](super::Interface) that shows the hint of a [`Tooltip`] next to its component.
+/// The layer only takes the space of the hint, so it never blocks the component being hovered.
+/// The hint is hidden when its component goes away, e.g. when the page changes.
+#[derive(Debug, Component)]
+pub struct TooltipLayer(Stack, Option<TooltipCard>, #[skip] Arc<Mutex<Option<Rect>>>, #[skip] Option<Arc<Mutex<Option<Rect>>>>, #[skip] Option<ElementID>);
+
+impl TooltipLayer {
+    pub fn new() -> Self {
+        TooltipLayer(Stack::default(), None, Arc::new(Mutex::new(None)), None, None)
+    }
+
+    /// Returns `true` if a tooltip is shown.
+    pub fn is_active(&self) -> bool { self.1.is_some() }
+
+    /// Hides the visible tooltip.
+    pub fn dismiss(&mut self) {
+        self.1 = None;
+        self.3 = None;
+        self.4 = None;
+        *self.2.lock().unwrap() = None;
+    }
+
+    /// Where the anchor of the visible tooltip is in the window, shared with the layout placing the layer.
+    pub(crate) fn anchor(&self) -> Arc<Mutex<Option<Rect>>> { self.2.clone() }
+
+    /// Asks the anchor of the visible tooltip where it is.
+    fn locate(&mut self, ctx: &mut Context) {
+        let Some(id) = self.4 else { return; };
+        let event = AnchorEvent::new(id);
+        self.3 = Some(event.target());
+        ctx.trigger_event(event);
+    }
+}
+
+impl Default for TooltipLayer {
+    fn default() -> Self { Self::new() }
+}
+
+impl OnEvent for TooltipLayer {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(ShowTooltipEvent{anchor, text}) = event.downcast_ref::<ShowTooltipEvent>() {
+            self.dismiss();
+            self.1 = Some(TooltipCard::new(ctx, text));
+            self.4 = Some(*anchor);
+            self.locate(ctx);
+        } else if let Some(HideTooltipEvent(id)) = event.downcast_ref::<HideTooltipEvent>() {
+            if self.4 == Some(*id) { self.dismiss(); }
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            let Some(located) = self.3.as_ref().map(|target| *target.lock().unwrap()) else { return true; };
+            let found = self.2.lock().unwrap().is_some();
+            match located {
+                Some(rect) => *self.2.lock().unwrap() = Some(rect),
+                // The anchor was found before but no longer reports, so it is gone.
+                None if found => {
+                    self.dismiss();
+                    return true;
+                },
+                None => {}
+            }
+            self.locate(ctx);
+        } else if let Some(MouseEvent{state: MouseState::Pressed, ..}) = event.downcast_ref::<MouseEvent>() {
+            self.dismiss();
+        }
+        true
+    }
+}
+
+#[derive(Debug, Component)]
+struct TooltipCard(Stack, OutlinedRectangle, Text);
+impl OnEvent for TooltipCard {}
+
+impl TooltipCard {
+    fn new(ctx: &mut Context, text: &str) -> Self {
+        let colors = &ctx.theme.colors;
+        let (background, outline) = (colors.background.secondary, colors.outline.secondary);
+        let font_size = ctx.theme.fonts.size.sm;
+        TooltipCard(
+            Stack(Offset::Center, Offset::Center, Size::Fit, Size::Fit, Padding(8.0, 4.0, 8.0, 4.0)),
+            OutlinedRectangle::new(background, outline, 4.0, 1.0),
+            Text::new(ctx, text, TextStyle::Primary, font_size, Align::Left)
+        )
+    }
+}
+
+/// Where the [`Interface`](super::Interface) places its [`TooltipLayer`] inside a window of `size`,
+/// centered under the `anchor` of the visible tooltip.
+pub(crate) fn tooltip_area(size: (f32, f32), request: &SizeRequest, anchor: Option<Rect>) -> Area {
+    let Some(anchor) = anchor else { return Area{offset: (0.0, 0.0), size: (0.0, 0.0)}; };
+    let tooltip = (request.min_width().min(size.0).max(0.0), request.min_height());
+    let (offset, size) = place_below(size, anchor, tooltip, true);
+    Area{offset, size}
+}
@@ -1,19 +1,18 @@
 use pelican_ui::{Area, Component, Context, Drawable, Event, Image, Layout, OnEvent, SizeRequest};
 
 use crate::elements::{Rectangle, AspectRatioImage};
-use crate::events::{NavigatorSelect, NavigateEvent, NavigatorEvent};
+use crate::events::{NavigatorSelect, NavigatorEvent};
 use crate::layout::{Column, Stack, Bin, Row, Padding, Offset, Size};
 use crate::components::{Button, ButtonState, IconButton};
 // use crate::components::avatar::{Avatar, AvatarContent};
 use crate::utils::ElementID;
 use crate::pages::AppPage;
-use crate::pages::Error;
 
 use std::fmt::Debug;
-use super::{NavigationButton, NavigateInfo, PageBuilder};
+use super::{NavigationButton, NavigateInfo, NavigatorTabs, PageHost};
 
 #[derive(Component)]
-pub struct WebInterface(Column, Option<WebNavigator>, Option<Box<dyn AppPage>>, Option<WebFooter>, #[skip] PageBuilder);
+pub struct WebInterface(Column, Option<WebNavigator>, PageHost, Option<WebFooter>, #[skip] Option<NavigatorTabs>);
 
 impl WebInterface {
     pub fn new(
@@ -21,34 +20,44 @@ impl WebInterface {
         start_page: Box<dyn AppPage>,
         mut navigation: Option<(usize, Vec<NavigateInfo>, Vec<NavigateInfo>)>,
         socials: Option<Vec<(&'static str, String)>>
+    ) -> Self {
+        let tabs = navigation.as_mut().map(NavigatorTabs::new);
+        Self::with_host(ctx, PageHost::new(start_page), tabs, navigation, socials)
+    }
+
+    pub(crate) fn with_host(
+        ctx: &mut Context, 
+        host: PageHost,
+        tabs: Option<NavigatorTabs>,
+        navigation: Option<(usize, Vec<NavigateInfo>, Vec<NavigateInfo>)>,
+        socials: Option<Vec<(&'static str, String)>>
     ) -> Self {
         // let color = ctx.theme.colors.outline.secondary;
-        let pages = navigation.as_mut().map(|navi| navi.1.iter_mut().chain(navi.2.iter_mut()).map(|t| t.3.take().unwrap()).collect::<Vec<_>>());
         let navigator = navigation.map(|n| WebNavigator::new(ctx, n));
         let footer = socials.map(|s| WebFooter::new(ctx, s)); 
 
         WebInterface(
             Column::new(0.0, Offset::Start, Size::fill(), Padding::default()),
             navigator,
-            Some(start_page),
+            host,
             footer,
-            pages,
+            tabs,
         )
     }
 
-    pub fn page(&mut self) -> &mut Option<Box<dyn AppPage>> { &mut self.2 }
+    pub fn page(&mut self) -> &mut Option<Box<dyn AppPage>> { self.2.page() }
+    pub fn host(&mut self) -> &mut PageHost { &mut self.2 }
+    pub fn tabs(&mut self) -> Option<&mut NavigatorTabs> { self.4.as_mut() }
     pub fn navigator(&mut self) -> &mut Option<WebNavigator> { &mut self.1 }
+
+    /// Consumes the interface, returning its pages and the navigator's tabs.
+    pub fn into_parts(self) -> (PageHost, Option<NavigatorTabs>) { (self.2, self.4) }
 }
 
 impl OnEvent for WebInterface {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
-        if let Some(NavigateEvent(index)) = event.downcast_mut::<NavigateEvent>() {
-            self.2 = match self.2.take().unwrap().navigate(ctx, *index) {
-                Ok(p) => Some(p),
-                Err(e) => Some(Box::new(Error::new(ctx, "404 Page Not Found", e)))
-            };
-        } else if let Some(NavigatorEvent(index)) = event.downcast_mut::<NavigatorEvent>() {
-            if let Some(nav) = self.4.as_mut() { self.2 = Some(nav[*index](ctx)); }
+        if let Some(NavigatorEvent(index)) = event.downcast_mut::<NavigatorEvent>() {
+            if let Some(tabs) = self.4.as_mut() { tabs.select(ctx, *index, &mut self.2); }
         }
         true
     }
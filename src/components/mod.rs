@@ -13,6 +13,7 @@ pub use common::{
     IconButton,
     QuickActions,
     Alert,
+    AlertStyle,
     Avatar,
     AvatarContent,
     AvatarIconStyle,
@@ -25,6 +26,39 @@ pub use common::{
     QRCode,
     QRCodeScanner,
     Slider,
+    LazyList,
+    SectionedList,
+    Section,
+    SectionHeader,
+    Toggle,
+    Checkbox,
+    CheckState,
+    RadioGroup,
+    SegmentedControl,
+    TabBar,
+    TabView,
+    Select,
+    ContextMenu,
+    DatePicker,
+    DateRangePicker,
+    TimePicker,
+    DateBounds,
+    AmountInput,
+    AmountUnit,
+    Passcode,
+    PasscodeMode,
+    MnemonicDisplay,
+    MnemonicInput,
+    Message,
+    MessageDirection,
+    MessageStatus,
+    MessageBubble,
+    MessageList,
+    MessageComposer,
+    Chart,
+    ProgressBar,
+    ProgressRing,
+    Spinner,
 };
 
 /// ## Interface
@@ -34,6 +68,15 @@ pub use common::{
 pub mod interface;
 pub use interface::{
     Interface, 
+    PageHost,
+    NavigatorTabs,
+    Modal,
+    ModalStyle,
+    ModalResult,
+    Popover,
+    Placement,
+    Tooltip,
+    Toast,
     Page,
     Bumper,
     Content, 
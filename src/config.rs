@@ -9,3 +9,126 @@ pub const IS_MOBILE: bool = false;
 pub const IS_WEB: bool = true;
 #[cfg(not(target_arch = "wasm32"))]
 pub const IS_WEB: bool = false;
+
+use crate::utils::ElementID;
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static BREAKPOINTS: Mutex<Breakpoints> = Mutex::new(Breakpoints::DEFAULT);
+static MODE: Mutex<Option<InterfaceMode>> = Mutex::new(None);
+static REDUCE_MOTION: AtomicBool = AtomicBool::new(false);
+static FOCUSED_INPUT: Mutex<Option<ElementID>> = Mutex::new(None);
+
+/// The shell used by the [`Interface`](crate::Interface).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterfaceMode {
+    Mobile,
+    Desktop,
+    Web,
+}
+
+impl InterfaceMode {
+    /// The mode picked from the compile-time platform before the window size is known.
+    pub fn platform() -> Self {
+        match (IS_MOBILE, IS_WEB) {
+            (true, _) => InterfaceMode::Mobile,
+            (false, true) => InterfaceMode::Web,
+            (false, false) => InterfaceMode::Desktop,
+        }
+    }
+}
+
+/// Window widths at which the [`Interface`](crate::Interface) changes its shell.
+///
+/// - Windows narrower than `compact` use the mobile shell.
+/// - On web, `wide` is also the max width of [`Content`](crate::Content) and [`Bumper`](crate::Bumper).
+///
+/// ```rust
+/// pelican_ui_std::set_breakpoints(Breakpoints { compact: 500.0, ..Breakpoints::default() });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Breakpoints {
+    pub compact: f32,
+    pub wide: f32,
+}
+
+impl Breakpoints {
+    pub const DEFAULT: Breakpoints = Breakpoints { compact: 600.0, wide: 1200.0 };
+
+    /// Returns the mode for a window of the given width. Mobile platforms always use [`InterfaceMode::Mobile`].
+    pub fn mode(&self, width: f32) -> InterfaceMode {
+        match InterfaceMode::platform() {
+            InterfaceMode::Mobile => InterfaceMode::Mobile,
+            _ if width < self.compact => InterfaceMode::Mobile,
+            platform => platform,
+        }
+    }
+
+    /// Returns the max width of page content, given the theme's max for the current mode.
+    pub fn content_max(&self, mode: InterfaceMode, max: f32) -> f32 {
+        match mode {
+            InterfaceMode::Web => self.wide,
+            _ => max,
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self { Breakpoints::DEFAULT }
+}
+
+/// Returns the breakpoints used by the [`Interface`](crate::Interface).
+pub fn breakpoints() -> Breakpoints {
+    *BREAKPOINTS.lock().unwrap()
+}
+
+/// Sets the breakpoints used by the [`Interface`](crate::Interface). Takes effect on the next frame.
+pub fn set_breakpoints(breakpoints: Breakpoints) {
+    *BREAKPOINTS.lock().unwrap() = breakpoints;
+}
+
+/// Returns the shell the [`Interface`](crate::Interface) is currently showing.
+pub fn interface_mode() -> InterfaceMode {
+    MODE.lock().unwrap().unwrap_or_else(InterfaceMode::platform)
+}
+
+pub(crate) fn set_interface_mode(mode: InterfaceMode) {
+    *MODE.lock().unwrap() = Some(mode);
+}
+
+/// Returns the max width of page content for the current mode and breakpoints.
+pub(crate) fn content_max(max: f32) -> f32 {
+    breakpoints().content_max(interface_mode(), max)
+}
+
+/// Returns `true` if animations that move content, like sliding page transitions, should be avoided.
+pub fn reduce_motion() -> bool {
+    REDUCE_MOTION.load(Ordering::Relaxed)
+}
+
+/// Sets whether animations that move content should be avoided. Page transitions fall back to a fade.
+pub fn set_reduce_motion(reduce: bool) {
+    REDUCE_MOTION.store(reduce, Ordering::Relaxed);
+}
+
+/// Returns `true` while a text input has focus, so keys like Escape belong to the input.
+pub(crate) fn input_focused() -> bool {
+    FOCUSED_INPUT.lock().unwrap().is_some()
+}
+
+/// Records whether the input `id` has focus. Losing focus only clears the record if it belongs to `id`.
+pub(crate) fn set_input_focus(id: ElementID, focused: bool) {
+    let mut input = FOCUSED_INPUT.lock().unwrap();
+    match focused {
+        true => *input = Some(id),
+        false if *input == Some(id) => *input = None,
+        false => {}
+    }
+}
+
+/// Forgets the focused input. The [`Interface`](crate::Interface) does this at the start of every frame,
+/// before the inputs record their focus again, so an input that is no longer showing can't keep the record.
+pub(crate) fn clear_input_focus() {
+    *FOCUSED_INPUT.lock().unwrap() = None;
+}
@@ -1,6 +1,12 @@
 use pelican_ui::Event;
 use pelican_ui::Context;
 use crate::utils::ElementID;
+use crate::layout::{Offset, ScrollPosition};
+use crate::pages::AppPage;
+use crate::components::{Modal, ModalResult, Popover, Toast};
+
+use std::any::TypeId;
+use std::sync::{Arc, Mutex};
 
 /// Event used to navigate between pages of the app.
 #[derive(Debug, Clone)]
@@ -12,6 +18,234 @@ impl Event for NavigateEvent {
     }
 }
 
+/// Event used to move through the navigation history of the [`Interface`](crate::Interface).
+///
+/// ```rust
+/// let button = Button::primary(ctx, "Continue", |ctx: &mut Context| {
+///     let page = Confirm::new(ctx);
+///     ctx.trigger_event(NavigationEvent::push(page));
+/// });
+/// let back = IconButton::navigation(ctx, "left", |ctx: &mut Context| ctx.trigger_event(NavigationEvent::Pop));
+/// ```
+#[derive(Debug, Clone)]
+pub enum NavigationEvent {
+    /// Shows a new page, keeping the current page in the history.
+    Push(PageSlot),
+    /// Shows a new page in place of the current page.
+    Replace(PageSlot),
+    /// Goes back to the previous page.
+    Pop,
+    /// Goes forward to the page that was last popped.
+    Forward,
+    /// Goes back to the first page in the history.
+    PopToRoot,
+    /// Goes back to the most recent page of the given type, see [`NavigationEvent::pop_to`].
+    PopTo(TypeId),
+}
+
+impl NavigationEvent {
+    pub fn push(page: impl AppPage) -> Self {
+        NavigationEvent::Push(PageSlot::new(Box::new(page)))
+    }
+
+    pub fn replace(page: impl AppPage) -> Self {
+        NavigationEvent::Replace(PageSlot::new(Box::new(page)))
+    }
+
+    pub fn pop_to<T: AppPage>() -> Self {
+        NavigationEvent::PopTo(TypeId::of::<T>())
+    }
+}
+
+impl Event for NavigationEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// A value carried by an event to exactly one receiver. The first receiver to take it gets the value.
+#[derive(Debug)]
+pub struct Slot<T>(Arc<Mutex<Option<T>>>);
+
+impl<T> Slot<T> {
+    pub fn new(value: T) -> Self {
+        Slot(Arc::new(Mutex::new(Some(value))))
+    }
+
+    pub fn take(&self) -> Option<T> {
+        self.0.lock().unwrap().take()
+    }
+}
+
+impl<T> Clone for Slot<T> {
+    fn clone(&self) -> Self { Slot(self.0.clone()) }
+}
+
+/// A page carried by a [`NavigationEvent`].
+pub type PageSlot = Slot<Box<dyn AppPage>>;
+
+/// Event used to show a [`Modal`](crate::Modal) over the current page.
+///
+/// ```rust
+/// let modal = Modal::dialog(ctx, "Delete contact?", "This can't be undone.", Some("Delete"), Some("Cancel"), |ctx: &mut Context, result: ModalResult| {
+///     if result == ModalResult::Primary { ctx.trigger_event(DeleteContact(id)); }
+/// });
+/// ctx.trigger_event(PresentModalEvent::new(modal));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PresentModalEvent(pub Slot<Modal>);
+
+impl PresentModalEvent {
+    pub fn new(modal: Modal) -> Self {
+        PresentModalEvent(Slot::new(modal))
+    }
+}
+
+impl Event for PresentModalEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Event used to dismiss the top-most [`Modal`](crate::Modal), passing `result` to its callback.
+#[derive(Debug, Clone)]
+pub struct DismissModalEvent(pub ModalResult);
+
+impl Event for DismissModalEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Event used to show a [`Toast`](crate::Toast). Toasts are queued and shown one at a time.
+///
+/// ```rust
+/// let toast = Toast::new(ctx, "Address copied", AlertStyle::Info);
+/// ctx.trigger_event(ToastEvent::new(toast));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ToastEvent(pub Slot<Toast>);
+
+impl ToastEvent {
+    pub fn new(toast: Toast) -> Self {
+        ToastEvent(Slot::new(toast))
+    }
+}
+
+impl Event for ToastEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Event used to hide the visible [`Toast`](crate::Toast) before its duration is up.
+#[derive(Debug, Clone)]
+pub struct DismissToastEvent;
+
+impl Event for DismissToastEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Event used to show a [`Popover`](crate::Popover) next to the component it is anchored to.
+/// Only one popover is shown at a time, so presenting a popover replaces the visible one.
+///
+/// ```rust
+/// let popover = Popover::below(ctx, select_id, Box::new(menu));
+/// ctx.trigger_event(PresentPopoverEvent::new(popover));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PresentPopoverEvent(pub Slot<Popover>);
+
+impl PresentPopoverEvent {
+    pub fn new(popover: Popover) -> Self {
+        PresentPopoverEvent(Slot::new(popover))
+    }
+}
+
+impl Event for PresentPopoverEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Event used to hide the visible [`Popover`](crate::Popover).
+#[derive(Debug, Clone)]
+pub struct DismissPopoverEvent;
+
+impl Event for DismissPopoverEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Event used to show a tooltip with `text` next to the element with the `anchor` id.
+///
+/// [`Tooltip`](crate::Tooltip)s trigger this after a hover or a long-press.
+#[derive(Debug, Clone)]
+pub struct ShowTooltipEvent {
+    pub anchor: ElementID,
+    pub text: String,
+}
+
+impl Event for ShowTooltipEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Event used to hide the tooltip shown for the element with the given [`ElementID`].
+#[derive(Debug, Clone)]
+pub struct HideTooltipEvent(pub ElementID);
+
+impl Event for HideTooltipEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Event used to find where the element with the given [`ElementID`] is in the window,
+/// e.g. to place a [`Popover`](crate::Popover) next to it.
+///
+/// Components that own an [`ElementID`] report their area by calling [`AnchorEvent::report`].
+#[derive(Debug, Clone)]
+pub struct AnchorEvent {
+    pub id: ElementID,
+    position: (f32, f32),
+    size: (f32, f32),
+    target: Arc<Mutex<Option<((f32, f32), (f32, f32))>>>,
+}
+
+impl AnchorEvent {
+    pub fn new(id: ElementID) -> Self {
+        AnchorEvent{id, position: (0.0, 0.0), size: (0.0, 0.0), target: Arc::new(Mutex::new(None))}
+    }
+
+    /// Reports the receiving component as the anchor if `id` matches. Returns `true` on a match.
+    pub fn report(&self, id: ElementID) -> bool {
+        if id != self.id { return false; }
+        *self.target.lock().unwrap() = Some((self.position, self.size));
+        true
+    }
+
+    /// Returns where the anchor will be reported, as its offset and size in the window.
+    pub(crate) fn target(&self) -> Arc<Mutex<Option<((f32, f32), (f32, f32))>>> {
+        self.target.clone()
+    }
+}
+
+impl Event for AnchorEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|(offset, size)| {
+            let mut event = (*self).clone();
+            event.position = (self.position.0 + offset.0, self.position.1 + offset.1);
+            event.size = size;
+            Some(Box::new(event) as Box<dyn Event>)
+        }).collect()
+    }
+}
+
 /// Event used to bring up or hide the keyboard.
 #[derive(Debug, Clone)]
 pub struct KeyboardActiveEvent(pub Option<bool>);
@@ -42,6 +276,16 @@ impl Event for SetActiveInput {
     }
 }
 
+/// Sets the contents of the [`TextInput`] with the given [`ElementID`], e.g. from a picker opened by its icon button.
+#[derive(Debug, Clone)]
+pub struct SetInputValue(pub ElementID, pub String);
+
+impl Event for SetInputValue {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
 /// Selects the [`TextInput`] with the given [`ElementID`] and deselects all other items.
 #[derive(Debug, Clone)]
 pub struct TextInputSelect(pub ElementID);
@@ -72,6 +316,16 @@ impl Event for NavigatorSelect {
     }
 }
 
+/// Selects the tab or segment with the given [`ElementID`] and deselects the others in its [`TabBar`](crate::TabBar) or [`SegmentedControl`](crate::SegmentedControl).
+#[derive(Debug, Clone)]
+pub struct TabSelect(pub ElementID);
+
+impl Event for TabSelect {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
 /// Navigates to the page at the given `index`. See [`AppPage`] for details on navigation.
 #[derive(Debug, Clone)]
 pub struct NavigatorEvent(pub usize);
@@ -115,6 +369,85 @@ impl Event for AdjustScrollEvent {
     }
 }
 
+/// Scrolls the [`Content`] holding the element with the given [`ElementID`] until the element is in view.
+///
+/// `align` places the element at the start, center or end of the viewport. With `animated` the content
+/// settles on the new position over a few frames instead of jumping.
+/// Components that own an [`ElementID`] report their position by calling [`ScrollToEvent::report`].
+///
+/// ```rust
+/// ctx.trigger_event(ScrollToEvent::new(input.get_id(), Offset::Center, true));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScrollToEvent {
+    pub id: ElementID,
+    pub align: Offset,
+    pub animated: bool,
+    position: (f32, f32),
+    size: (f32, f32),
+    target: Arc<Mutex<Option<(f32, f32)>>>,
+}
+
+impl ScrollToEvent {
+    pub fn new(id: ElementID, align: Offset, animated: bool) -> Self {
+        ScrollToEvent{id, align, animated, position: (0.0, 0.0), size: (0.0, 0.0), target: Arc::new(Mutex::new(None))}
+    }
+
+    /// Reports the receiving component as the target if `id` matches. Returns `true` on a match.
+    pub fn report(&self, id: ElementID) -> bool {
+        if id != self.id { return false; }
+        *self.target.lock().unwrap() = Some((self.position.1, self.size.1));
+        true
+    }
+
+    /// Measures positions from the receiving component and returns where the target will be reported,
+    /// as its vertical offset and height.
+    pub(crate) fn capture(&mut self) -> Arc<Mutex<Option<(f32, f32)>>> {
+        self.position = (0.0, 0.0);
+        self.target = Arc::new(Mutex::new(None));
+        self.target.clone()
+    }
+}
+
+impl Event for ScrollToEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|(offset, size)| {
+            let mut event = (*self).clone();
+            event.position = (self.position.0 + offset.0, self.position.1 + offset.1);
+            event.size = size;
+            Some(Box::new(event) as Box<dyn Event>)
+        }).collect()
+    }
+}
+
+/// Event triggered by [`Content`] whenever its scroll position changes.
+///
+/// `offset` is the distance from the top of the content to the top of the viewport.
+#[derive(Debug, Clone)]
+pub struct ScrollChangedEvent {
+    pub id: ElementID,
+    pub offset: f32,
+    pub max_offset: f32,
+    pub at_top: bool,
+    pub at_bottom: bool,
+}
+
+impl Event for ScrollChangedEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Tells sticky sections where they sit inside the [`Content`] that scrolls them.
+#[derive(Debug, Clone)]
+pub(crate) struct StickyEvent(pub(crate) Option<ScrollPosition>, pub(crate) f32);
+
+impl Event for StickyEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|(offset, _)| Some(Box::new(StickyEvent(self.0.clone(), self.1 + offset.1)) as Box<dyn Event>)).collect()
+    }
+}
+
 /// Event triggered when the [`QRScanner`] component detects a QR code.
 #[derive(Debug, Clone)]
 pub struct QRCodeScannedEvent(pub String);
@@ -132,4 +465,19 @@ impl Event for AttachmentEvent {
     fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
         children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
     }
-}
\ No newline at end of file
+}
+
+/// Event triggered by the [`MessageComposer`](crate::MessageComposer) when a message is sent.
+///
+/// `attachments` are images encoded with [`EncodedImage::encode`](crate::EncodedImage::encode).
+#[derive(Debug, Clone)]
+pub struct MessageComposedEvent {
+    pub text: String,
+    pub attachments: Vec<String>,
+}
+
+impl Event for MessageComposedEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
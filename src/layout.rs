@@ -1,6 +1,7 @@
 use pelican_ui::{Context, Component, OnEvent, Drawable, Layout, Area, SizeRequest};
 
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
 
@@ -179,6 +180,127 @@ impl UniformExpand {
     }
 }
 
+/// Per-item settings used by a [`Row`] or [`Column`] in flex mode.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlexItem {
+    /// Weight used to share out free space along the main axis.
+    pub grow: f32,
+    /// Weight used to take space away when the layout is smaller than its items.
+    pub shrink: f32,
+    /// Cross-axis alignment overriding the layout's own [`Offset`].
+    pub align: Option<Offset>,
+}
+
+impl Default for FlexItem {
+    fn default() -> Self {
+        FlexItem{grow: 0.0, shrink: 1.0, align: None}
+    }
+}
+
+impl FlexItem {
+    pub fn new(grow: f32, shrink: f32, align: Option<Offset>) -> Self {
+        FlexItem{grow, shrink, align}
+    }
+
+    pub fn grow(grow: f32) -> Self {
+        FlexItem{grow, ..Default::default()}
+    }
+
+    pub fn align(align: Offset) -> Self {
+        FlexItem{align: Some(align), ..Default::default()}
+    }
+}
+
+/// Defines how a [`Row`] or [`Column`] in flex mode places its items along the main axis.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Justify {
+    #[default]
+    Start,
+    Center,
+    End,
+    /// Free space goes between the items, none at the edges.
+    SpaceBetween,
+    /// Every item gets the same space on both of its sides.
+    SpaceAround,
+    /// The space between items and at the edges is equal.
+    SpaceEvenly,
+}
+
+/// Flex settings for a [`Row`] or [`Column`].
+///
+/// Items without a [`FlexItem`] don't grow, shrink evenly and use the layout's alignment.
+///
+///```rust
+/// let flex = Flex::new(Justify::SpaceBetween, vec![FlexItem::grow(2.0), FlexItem::grow(1.0), FlexItem::align(Offset::End)]);
+/// let layout = Row::flexible(8.0, Offset::Center, Size::Fit, Padding::default(), flex);
+///```
+#[derive(Clone, Debug, Default)]
+pub struct Flex(pub Justify, pub Vec<FlexItem>);
+
+impl Flex {
+    pub fn new(justify: Justify, items: Vec<FlexItem>) -> Self {
+        Flex(justify, items)
+    }
+
+    pub fn item(&self, index: usize) -> FlexItem {
+        self.1.get(index).copied().unwrap_or_default()
+    }
+
+    fn request(&self, size: (f32, f32)) -> (f32, f32) {
+        match self.0 {
+            Justify::Start => size,
+            _ => (size.0, f32::MAX)
+        }
+    }
+
+    /// Returns the offset and size of every item along the main axis.
+    fn distribute(&self, sizes: Vec<(f32, f32)>, max_size: f32, spacing: f32) -> Vec<(f32, f32)> {
+        let count = sizes.len();
+        let mut lengths = sizes.iter().map(|s| s.0).collect::<Vec<_>>();
+        let mut free = max_size - lengths.iter().sum::<f32>() - spacing*(count.max(1)-1) as f32;
+
+        if free > 0.0 {
+            let mut growing = (0..count).filter(|i| self.item(*i).grow > 0.0 && lengths[*i] < sizes[*i].1).collect::<Vec<_>>();
+            while free > 0.0 && !growing.is_empty() {
+                let weight = growing.iter().map(|i| self.item(*i).grow).sum::<f32>();
+                let mut used = 0.0;
+                for i in &growing {
+                    let grown = (lengths[*i] + free * self.item(*i).grow / weight).min(sizes[*i].1);
+                    used += grown - lengths[*i];
+                    lengths[*i] = grown;
+                }
+                free -= used;
+                growing.retain(|i| lengths[*i] < sizes[*i].1);
+                if used <= 0.0 { break; }
+            }
+        } else if free < 0.0 {
+            let weight = (0..count).map(|i| self.item(i).shrink.max(0.0) * lengths[i]).sum::<f32>();
+            if weight > 0.0 {
+                let weights = (0..count).map(|i| self.item(i).shrink.max(0.0) * lengths[i]).collect::<Vec<_>>();
+                lengths.iter_mut().zip(weights).for_each(|(l, w)| *l = (*l + free * w / weight).max(0.0));
+            }
+        }
+
+        let free = free.max(0.0);
+        let (lead, gap) = match self.0 {
+            Justify::Start => (0.0, spacing),
+            Justify::Center => (free / 2.0, spacing),
+            Justify::End => (free, spacing),
+            Justify::SpaceBetween if count > 1 => (0.0, spacing + free / (count-1) as f32),
+            Justify::SpaceBetween => (0.0, spacing),
+            Justify::SpaceAround => (free / (2*count.max(1)) as f32, spacing + free / count.max(1) as f32),
+            Justify::SpaceEvenly => (free / (count+1) as f32, spacing + free / (count+1) as f32),
+        };
+
+        let mut offset = lead;
+        lengths.into_iter().map(|length| {
+            let item = (offset, length);
+            offset += length + gap;
+            item
+        }).collect()
+    }
+}
+
 /// Horizontal layout of items.
 ///
 /// <img src="https://raw.githubusercontent.com/ramp-stack/pelican_ui_std/main/src/examples/row.png"
@@ -189,11 +311,16 @@ impl UniformExpand {
 /// let layout = Row::new(24.0, Offset::Center, Size::Fit, Padding::new(8.0));
 ///```
 #[derive(Debug)]
-pub struct Row(f32, Offset, Size, Padding);
+pub struct Row(f32, Offset, Size, Padding, Option<Flex>);
 
 impl Row {
     pub fn new(spacing: f32, offset: Offset, size: Size, padding: Padding) -> Self {
-        Row(spacing, offset, size, padding)
+        Row(spacing, offset, size, padding, None)
+    }
+
+    /// Creates a row that places its items using [`Flex`] weights and justification.
+    pub fn flexible(spacing: f32, offset: Offset, size: Size, padding: Padding, flex: Flex) -> Self {
+        Row(spacing, offset, size, padding, Some(flex))
     }
 
     pub fn center(spacing: f32) -> Self {
@@ -201,6 +328,7 @@ impl Row {
     }
 
     pub fn padding(&mut self) -> &mut Padding {&mut self.3}
+    pub fn flex(&mut self) -> &mut Option<Flex> {&mut self.4}
 }
 
 impl Layout for Row {
@@ -210,6 +338,7 @@ impl Layout for Row {
         ).unzip();
         let spacing = self.0*(widths.len()-1) as f32;
         let width = Size::add(widths);
+        let width = self.4.as_ref().map(|f| f.request(width)).unwrap_or(width);
         let height = self.2.get(heights, Size::max);
         self.3.adjust_request(SizeRequest::new(width.0, height.0, width.1, height.1).add_width(spacing))
     }
@@ -217,6 +346,15 @@ impl Layout for Row {
     fn build(&self, _ctx: &mut Context, row_size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
         let row_size = self.3.adjust_size(row_size);
 
+        if let Some(flex) = &self.4 {
+            let spans = flex.distribute(children.iter().map(|i| (i.min_width(), i.max_width())).collect(), row_size.0, self.0);
+            return children.into_iter().zip(spans).enumerate().map(|(index, (i, (offset, width)))| {
+                let size = (width, i.get((width, row_size.1)).1);
+                let align = flex.item(index).align.unwrap_or(self.1);
+                Area{offset: self.3.adjust_offset((offset, align.get(row_size.1, size.1))), size}
+            }).collect();
+        }
+
         let widths = UniformExpand::get(children.iter().map(|i| (i.min_width(), i.max_width())).collect::<Vec<_>>(), row_size.0, self.0);
 
         let mut offset = 0.0;
@@ -239,18 +377,24 @@ impl Layout for Row {
 /// let layout = Column::new(24.0, Offset::Center, Size::Fit, Padding::new(8.0));
 ///```
 #[derive(Debug)]
-pub struct Column(f32, Offset, Size, Padding);
+pub struct Column(f32, Offset, Size, Padding, Option<Flex>);
 
 impl Column {
     pub fn new(spacing: f32, offset: Offset, size: Size, padding: Padding) -> Self {
-        Column(spacing, offset, size, padding)
+        Column(spacing, offset, size, padding, None)
+    }
+
+    /// Creates a column that places its items using [`Flex`] weights and justification.
+    pub fn flexible(spacing: f32, offset: Offset, size: Size, padding: Padding, flex: Flex) -> Self {
+        Column(spacing, offset, size, padding, Some(flex))
     }
 
     pub fn center(spacing: f32) -> Self {
-        Column(spacing, Offset::Center, Size::Fit, Padding::default())
+        Column(spacing, Offset::Center, Size::Fit, Padding::default(), None)
     }
 
     pub fn padding(&mut self) -> &mut Padding {&mut self.3}
+    pub fn flex(&mut self) -> &mut Option<Flex> {&mut self.4}
 }
 
 impl Layout for Column {
@@ -261,12 +405,22 @@ impl Layout for Column {
         let spacing = self.0*(heights.len()-1) as f32;
         let width = self.2.get(widths, Size::max);
         let height = Size::add(heights);
+        let height = self.4.as_ref().map(|f| f.request(height)).unwrap_or(height);
         self.3.adjust_request(SizeRequest::new(width.0, height.0, width.1, height.1).add_height(spacing))
     }
 
     fn build(&self, _ctx: &mut Context, col_size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
         let col_size = self.3.adjust_size(col_size);
 
+        if let Some(flex) = &self.4 {
+            let spans = flex.distribute(children.iter().map(|i| (i.min_height(), i.max_height())).collect(), col_size.1, self.0);
+            return children.into_iter().zip(spans).enumerate().map(|(index, (i, (offset, height)))| {
+                let size = (i.get((col_size.0, height)).0, height);
+                let align = flex.item(index).align.unwrap_or(self.1);
+                Area{offset: self.3.adjust_offset((align.get(col_size.0, size.0), offset)), size}
+            }).collect();
+        }
+
         let heights = UniformExpand::get(children.iter().map(|i| (i.min_height(), i.max_height())).collect::<Vec<_>>(), col_size.1, self.0);
 
         let mut offset = 0.0;
@@ -408,6 +562,190 @@ impl Layout for Wrap {
 }
 
 
+/// Sizing rule for a single row or column of a [`Grid`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GridTrack {
+    /// Track uses a fixed, static size.
+    Static(f32),
+    /// Track takes a weighted share of the space left over after the other tracks.
+    Fraction(f32),
+    /// Track fits the largest minimum size of the items placed in it.
+    Fit,
+}
+
+/// Two dimensional layout of items placed into rows and columns.
+///
+/// Items are placed left to right, moving to the next row when the columns are full.
+/// Rows without a track default to [`GridTrack::Fit`]. Items can span several rows or columns.
+///
+///```rust
+/// let mut layout = Grid::new(
+///     vec![GridTrack::Static(48.0), GridTrack::Fraction(1.0), GridTrack::Fit],
+///     vec![],
+///     (16.0, 8.0),
+///     (Offset::Start, Offset::Center),
+///     Padding::new(8.0)
+/// );
+/// layout.span(0, 3, 1);
+///```
+#[derive(Debug)]
+pub struct Grid(Vec<GridTrack>, Vec<GridTrack>, (f32, f32), (Offset, Offset), Padding, Vec<(usize, usize)>);
+
+impl Grid {
+    pub fn new(columns: Vec<GridTrack>, rows: Vec<GridTrack>, spacing: (f32, f32), offset: (Offset, Offset), padding: Padding) -> Self {
+        Grid(columns, rows, spacing, offset, padding, Vec::new())
+    }
+
+    /// Creates a grid of equally sized columns.
+    pub fn uniform(columns: usize, spacing: f32) -> Self {
+        Grid::new(vec![GridTrack::Fraction(1.0); columns], vec![], (spacing, spacing), (Offset::Center, Offset::Center), Padding::default())
+    }
+
+    /// Sets how many columns and rows the item at `index` spans.
+    pub fn span(&mut self, index: usize, columns: usize, rows: usize) {
+        if self.5.len() <= index { self.5.resize(index+1, (1, 1)); }
+        self.5[index] = (columns, rows);
+    }
+
+    pub fn columns(&mut self) -> &mut Vec<GridTrack> {&mut self.0}
+    pub fn rows(&mut self) -> &mut Vec<GridTrack> {&mut self.1}
+    pub fn padding(&mut self) -> &mut Padding {&mut self.4}
+
+    /// Returns the (column, row, column span, row span) of every item and the total row count.
+    fn place(&self, count: usize) -> (Vec<(usize, usize, usize, usize)>, usize) {
+        let columns = self.0.len().max(1);
+        let mut taken: Vec<Vec<bool>> = Vec::new();
+        let mut cells = Vec::with_capacity(count);
+        let (mut row, mut col) = (0, 0);
+
+        for index in 0..count {
+            let (cs, rs) = self.5.get(index).copied().unwrap_or((1, 1));
+            let (cs, rs) = (cs.clamp(1, columns), rs.max(1));
+            loop {
+                if col + cs > columns {
+                    col = 0;
+                    row += 1;
+                    continue;
+                }
+                let free = (row..row+rs).all(|r| (col..col+cs).all(|c| !taken.get(r).map(|l| l[c]).unwrap_or(false)));
+                if free { break; }
+                col += 1;
+            }
+
+            while taken.len() < row+rs { taken.push(vec![false; columns]); }
+            (row..row+rs).for_each(|r| (col..col+cs).for_each(|c| taken[r][c] = true));
+            cells.push((col, row, cs, rs));
+            col += cs;
+        }
+
+        (cells, taken.len().max(self.1.len()))
+    }
+
+    /// Measures the (min, max) size of each track from the items placed in it.
+    fn measure(tracks: &[GridTrack], count: usize, items: Vec<(usize, usize, f32)>, spacing: f32) -> Vec<(f32, f32)> {
+        let track = |i: usize| tracks.get(i).copied().unwrap_or(GridTrack::Fit);
+        let mut sizes = (0..count).map(|i| match track(i) {
+            GridTrack::Static(s) => (s, s),
+            GridTrack::Fraction(_) => (0.0, f32::MAX),
+            GridTrack::Fit => (0.0, 0.0),
+        }).collect::<Vec<_>>();
+
+        for &(start, _, min) in items.iter().filter(|i| i.1 == 1) {
+            if matches!(track(start), GridTrack::Static(_)) { continue; }
+            sizes[start].0 = sizes[start].0.max(min);
+            if track(start) == GridTrack::Fit { sizes[start].1 = sizes[start].0; }
+        }
+
+        // Items spanning several tracks grow the flexible tracks they cover when they don't fit.
+        for (start, span, min) in items.into_iter().filter(|i| i.1 > 1) {
+            let current = sizes[start..start+span].iter().map(|s| s.0).sum::<f32>() + spacing*(span-1) as f32;
+            let flexible = (start..start+span).filter(|i| !matches!(track(*i), GridTrack::Static(_))).collect::<Vec<_>>();
+            if min <= current || flexible.is_empty() { continue; }
+            let extra = (min - current) / flexible.len() as f32;
+            for i in flexible {
+                sizes[i].0 += extra;
+                if track(i) == GridTrack::Fit { sizes[i].1 = sizes[i].0; }
+            }
+        }
+
+        sizes
+    }
+
+    /// Resolves the final size of each track, sharing the free space between fractional tracks.
+    fn resolve(tracks: &[GridTrack], sizes: Vec<(f32, f32)>, max_size: f32, spacing: f32) -> Vec<f32> {
+        let fraction = |i: usize| match tracks.get(i) {
+            Some(GridTrack::Fraction(f)) => Some(f.max(0.0)),
+            _ => None
+        };
+
+        let mut result = sizes.iter().map(|s| s.0).collect::<Vec<_>>();
+        let fixed = (0..sizes.len()).filter(|i| fraction(*i).is_none()).map(|i| sizes[i].0).sum::<f32>();
+        let spacing = spacing*(sizes.len().max(1)-1) as f32;
+        let mut free = (max_size - fixed - spacing).max(0.0);
+        let mut flexible = (0..sizes.len()).filter(|i| fraction(*i).is_some()).collect::<Vec<_>>();
+
+        // Tracks whose share would be smaller than their minimum keep their minimum and leave the pool.
+        loop {
+            let weight = flexible.iter().map(|i| fraction(*i).unwrap()).sum::<f32>();
+            if weight <= 0.0 { break; }
+            let (clamped, rest): (Vec<usize>, Vec<usize>) = flexible.iter().copied().partition(|i| free * fraction(*i).unwrap() / weight < sizes[*i].0);
+            if clamped.is_empty() {
+                rest.into_iter().for_each(|i| result[i] = free * fraction(i).unwrap() / weight);
+                break;
+            }
+            clamped.into_iter().for_each(|i| free = (free - sizes[i].0).max(0.0));
+            flexible = rest;
+        }
+
+        result
+    }
+
+    fn span_of(sizes: &[f32], start: usize, span: usize, spacing: f32) -> (f32, f32) {
+        let offset = sizes[..start].iter().map(|s| s + spacing).sum::<f32>();
+        let length = sizes[start..start+span].iter().sum::<f32>() + spacing*(span-1) as f32;
+        (offset, length)
+    }
+}
+
+impl Layout for Grid {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let (cells, rows) = self.place(children.len());
+        let columns = self.0.len().max(1);
+
+        let widths = Self::measure(&self.0, columns, cells.iter().zip(&children).map(|(c, r)| (c.0, c.2, r.min_width())).collect(), self.2.0);
+        let heights = Self::measure(&self.1, rows, cells.iter().zip(&children).map(|(c, r)| (c.1, c.3, r.min_height())).collect(), self.2.1);
+
+        let total = |sizes: Vec<(f32, f32)>, spacing: f32| {
+            let spacing = spacing*(sizes.len().max(1)-1) as f32;
+            let (min, max) = Size::add(sizes);
+            (min+spacing, if max >= f32::MAX {f32::MAX} else {max+spacing})
+        };
+
+        let width = total(widths, self.2.0);
+        let height = total(heights, self.2.1);
+        self.4.adjust_request(SizeRequest::new(width.0, height.0, width.1, height.1))
+    }
+
+    fn build(&self, _ctx: &mut Context, grid_size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let grid_size = self.4.adjust_size(grid_size);
+        let (cells, rows) = self.place(children.len());
+        let columns = self.0.len().max(1);
+
+        let widths = Self::measure(&self.0, columns, cells.iter().zip(&children).map(|(c, r)| (c.0, c.2, r.min_width())).collect(), self.2.0);
+        let heights = Self::measure(&self.1, rows, cells.iter().zip(&children).map(|(c, r)| (c.1, c.3, r.min_height())).collect(), self.2.1);
+        let widths = Self::resolve(&self.0, widths, grid_size.0, self.2.0);
+        let heights = Self::resolve(&self.1, heights, grid_size.1, self.2.1);
+
+        children.into_iter().zip(cells).map(|(i, (col, row, cs, rs))| {
+            let (x, w) = Self::span_of(&widths, col, cs, self.2.0);
+            let (y, h) = Self::span_of(&heights, row, rs, self.2.1);
+            let size = i.get((w, h));
+            let offset = (x + self.3.0.get(w, size.0), y + self.3.1.get(h, size.1));
+            Area{offset: self.4.adjust_offset(offset), size}
+        }).collect()
+    }
+}
+
 /// Defines the reference point for scrolling content.
 #[derive(Debug, Clone, Copy)]
 pub enum ScrollAnchor {
@@ -415,11 +753,255 @@ pub enum ScrollAnchor {
     End,
 }
 
+/// Defines where a [`Scroll`] layout comes to rest once it stops moving.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum ScrollSnap {
+    #[default]
+    None,
+    /// Rest with the start of a child aligned to the start of the viewport.
+    Children,
+    /// Rest on multiples of a fixed distance, e.g. the page width of a pager.
+    Interval(f32),
+}
+
+/// Configures how a [`Scroll`] layout moves after the user lets go.
+///
+///```rust
+/// let mut layout = Scroll::horizontal(Offset::Start, Offset::Start, Size::fill(), Size::Fit, Padding::default(), ScrollAnchor::Start);
+/// layout.set_physics(ScrollPhysics::pager());
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScrollPhysics {
+    /// Keep scrolling with the release velocity and slow down over time.
+    pub momentum: bool,
+    /// Fraction of the velocity that is kept after one second, between 0.0 and 1.0.
+    pub deceleration: f32,
+    /// Allow dragging past the edges with resistance, springing back on release.
+    pub overscroll: bool,
+    /// Where the scroll comes to rest.
+    pub snap: ScrollSnap,
+}
+
+impl Default for ScrollPhysics {
+    fn default() -> Self {
+        ScrollPhysics{
+            momentum: crate::config::IS_MOBILE,
+            deceleration: 0.05,
+            overscroll: crate::config::IS_MOBILE,
+            snap: ScrollSnap::None,
+        }
+    }
+}
+
+impl ScrollPhysics {
+    /// Scrolls exactly by the input and stops when the input stops.
+    pub fn none() -> Self {
+        ScrollPhysics{momentum: false, deceleration: 0.0, overscroll: false, snap: ScrollSnap::None}
+    }
+
+    /// Momentum and overscroll bounce, regardless of platform.
+    pub fn kinetic() -> Self {
+        ScrollPhysics{momentum: true, deceleration: 0.05, overscroll: true, snap: ScrollSnap::None}
+    }
+
+    /// Snaps to one child at a time.
+    pub fn pager() -> Self {
+        ScrollPhysics{momentum: false, deceleration: 0.0, overscroll: true, snap: ScrollSnap::Children}
+    }
+}
+
+/// Motion state shared between a [`Scroll`] layout and its owner.
+///
+/// [`Scroll::new`] and [`Scroll::horizontal`] create one, so it is only needed when building the variants directly.
+///
+///```rust
+/// let layout = Scroll::Vertical(
+///     Offset::Start, Offset::Start, Size::fill(), Size::fill(), Padding::default(),
+///     Arc::new(Mutex::new(0.0)), ScrollAnchor::Start, ScrollMotion::new(ScrollPhysics::default())
+/// );
+///```
+#[derive(Debug, Default)]
+pub struct ScrollMotion {
+    physics: ScrollPhysics,
+    velocity: f32,
+    held: bool,
+    last_input: Option<Instant>,
+    last_tick: Option<Instant>,
+    target: Option<f32>,
+    max: f32,
+    viewport: f32,
+    offset: f32,
+    points: Vec<f32>,
+}
+
+impl ScrollMotion {
+    const RESISTANCE: f32 = 0.35;
+    const MIN_VELOCITY: f32 = 20.0;
+    const SETTLE_SPEED: f32 = 12.0;
+
+    pub fn new(physics: ScrollPhysics) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(ScrollMotion{physics, ..Default::default()}))
+    }
+
+    pub(crate) fn input(&mut self, value: f32, delta: f32) -> f32 {
+        let now = Instant::now();
+        let dt = self.last_input.map(|t| now.duration_since(t).as_secs_f32()).unwrap_or(f32::MAX);
+        self.velocity = match dt < 0.1 {
+            true => self.velocity * 0.2 + (delta / dt.max(1.0 / 120.0)) * 0.8,
+            false => 0.0
+        };
+        self.last_input = Some(now);
+        self.target = None;
+
+        let outside = (value < 0.0 && delta < 0.0) || (value > self.max && delta > 0.0);
+        match self.physics.overscroll && outside {
+            true => value + delta * Self::RESISTANCE,
+            false => value + delta
+        }
+    }
+
+    pub(crate) fn hold(&mut self) {
+        self.held = true;
+        self.velocity = 0.0;
+        self.target = None;
+    }
+
+    pub(crate) fn release(&mut self) {
+        self.held = false;
+        let idle = self.last_input.map(|t| t.elapsed().as_secs_f32() > 0.1).unwrap_or(true);
+        if idle { self.velocity = 0.0; }
+    }
+
+    fn snap_point(&self, value: f32) -> Option<f32> {
+        match self.physics.snap {
+            ScrollSnap::None => None,
+            ScrollSnap::Interval(i) if i > 0.0 => Some(((value / i).round() * i).clamp(0.0, self.max)),
+            ScrollSnap::Interval(_) => None,
+            ScrollSnap::Children => self.points.iter().copied().min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs())),
+        }
+    }
+
+    pub(crate) fn tick(&mut self, value: f32) -> f32 {
+        let now = Instant::now();
+        let dt = self.last_tick.map(|t| now.duration_since(t).as_secs_f32()).unwrap_or(0.0).min(0.1);
+        self.last_tick = Some(now);
+
+        let idle = self.last_input.map(|t| now.duration_since(t).as_secs_f32() > 0.05).unwrap_or(true);
+        if dt <= 0.0 || self.held || !idle { return value; }
+
+        if let Some(target) = self.target {
+            let next = value + (target - value) * (1.0 - (-dt * Self::SETTLE_SPEED).exp());
+            if (target - next).abs() >= 0.5 { return next; }
+            self.target = None;
+            return target;
+        }
+
+        let mut value = value;
+        if self.velocity != 0.0 && self.physics.snap != ScrollSnap::None {
+            // Project the fling forward and settle on the closest snap point.
+            self.target = self.snap_point(value + self.velocity * 0.2);
+            self.velocity = 0.0;
+            return value;
+        } else if self.physics.momentum && self.velocity.abs() > Self::MIN_VELOCITY {
+            value += self.velocity * dt;
+            self.velocity *= self.physics.deceleration.clamp(0.0, 1.0).powf(dt);
+            if value < 0.0 || value > self.max {
+                match self.physics.overscroll {
+                    true => self.velocity *= 0.1f32.powf(dt * 10.0),
+                    false => self.velocity = 0.0,
+                }
+            }
+            return value;
+        }
+        self.velocity = 0.0;
+
+        if value < 0.0 || value > self.max {
+            self.target = Some(value.clamp(0.0, self.max));
+        } else if let Some(point) = self.snap_point(value).filter(|p| (p - value).abs() >= 0.5) {
+            self.target = Some(point);
+        }
+        value
+    }
+
+    /// Records the layout results and returns the allowed scroll value.
+    pub(crate) fn layout(&mut self, value: f32, max: f32, viewport: f32, sizes: &[f32], anchor: ScrollAnchor) -> f32 {
+        self.max = max;
+        self.viewport = viewport;
+        self.points = sizes.iter().scan(0.0, |offset, size| {
+            let start = *offset;
+            *offset += size;
+            Some(start.min(max))
+        }).map(|p| match anchor {
+            ScrollAnchor::Start => p,
+            ScrollAnchor::End => max - p,
+        }).collect();
+
+        let value = match self.physics.overscroll {
+            true => value.clamp(-viewport / 3.0, max + viewport / 3.0),
+            false => value.clamp(0.0, max)
+        };
+        self.offset = match anchor {
+            ScrollAnchor::Start => value,
+            ScrollAnchor::End => max - value,
+        };
+        value
+    }
+
+    /// Returns the value that places an item at `position` from the start of the viewport, with the given `size`, according to `align`.
+    pub(crate) fn scroll_target(&self, value: f32, position: f32, size: f32, align: Offset, anchor: ScrollAnchor) -> f32 {
+        let distance = position - align.get(self.viewport, size);
+        match anchor {
+            ScrollAnchor::Start => value + distance,
+            ScrollAnchor::End => value - distance,
+        }.clamp(0.0, self.max)
+    }
+
+    /// Stops any movement and moves to `target`, settling over a few frames when `animated`. Returns the new value.
+    pub(crate) fn move_to(&mut self, value: f32, target: f32, animated: bool) -> f32 {
+        self.velocity = 0.0;
+        self.target = animated.then_some(target);
+        if animated {value} else {target}
+    }
+
+    pub(crate) fn set_physics(&mut self, physics: ScrollPhysics) { self.physics = physics; }
+
+    /// The largest scroll value from the last layout.
+    pub(crate) fn max(&self) -> f32 { self.max }
+
+    /// Returns `true` while coasting or settling.
+    pub(crate) fn is_moving(&self) -> bool { self.target.is_some() || self.velocity != 0.0 }
+}
+
+/// Live handle to the position of a [`Scroll`] layout, measured from the start of its content.
+#[derive(Debug, Clone)]
+pub(crate) struct ScrollPosition(Arc<Mutex<f32>>, ScrollAnchor, Arc<Mutex<ScrollMotion>>);
+
+impl ScrollPosition {
+    /// The current offset, including any overscroll.
+    pub(crate) fn offset(&self) -> f32 {
+        let value = *self.0.lock().unwrap();
+        match self.1 {
+            ScrollAnchor::Start => value,
+            ScrollAnchor::End => self.2.lock().unwrap().max - value,
+        }
+    }
+
+    /// The offset used by the last layout.
+    pub(crate) fn built(&self) -> f32 {
+        self.2.lock().unwrap().offset
+    }
+}
+
 /// Scrollable layout of items.
+///
+/// Children are placed one after another along the scroll axis.
+/// Owners forward input through [`adjust_scroll`](Scroll::adjust_scroll), [`hold`](Scroll::hold)
+/// and [`release`](Scroll::release), and call [`tick`](Scroll::tick) on every `TickEvent`
+/// so momentum, overscroll bounce and snapping can play out. See [`ScrollPhysics`].
 #[derive(Debug)]
 pub enum Scroll {
-    Vertical(Offset, Offset, Size, Size, Padding, Arc<Mutex<f32>>, ScrollAnchor),
-    Horizontal(Offset, Offset, Size, Size, Padding, Arc<Mutex<f32>>, ScrollAnchor)
+    Vertical(Offset, Offset, Size, Size, Padding, Arc<Mutex<f32>>, ScrollAnchor, Arc<Mutex<ScrollMotion>>),
+    Horizontal(Offset, Offset, Size, Size, Padding, Arc<Mutex<f32>>, ScrollAnchor, Arc<Mutex<ScrollMotion>>)
 }
 
 impl Default for Scroll {
@@ -430,44 +1012,101 @@ impl Default for Scroll {
 
 impl Scroll {
     pub fn new(offset_x: Offset, offset_y: Offset, size_x: Size, size_y: Size, padding: Padding, anchor: ScrollAnchor) -> Self {
-        Scroll::Vertical(offset_x, offset_y, size_x, size_y, padding, Arc::new(Mutex::new(0.0)), anchor)
+        Scroll::Vertical(offset_x, offset_y, size_x, size_y, padding, Arc::new(Mutex::new(0.0)), anchor, ScrollMotion::new(ScrollPhysics::default()))
     }
 
     pub fn horizontal(offset_x: Offset, offset_y: Offset, size_x: Size, size_y: Size, padding: Padding, anchor: ScrollAnchor) -> Self {
-        Scroll::Horizontal(offset_x, offset_y, size_x, size_y, padding, Arc::new(Mutex::new(0.0)), anchor)
+        Scroll::Horizontal(offset_x, offset_y, size_x, size_y, padding, Arc::new(Mutex::new(0.0)), anchor, ScrollMotion::new(ScrollPhysics::default()))
     }
 
-    pub fn adjust_scroll(&mut self, delta: f32) { 
-        println!("ADJUSTING SCROLL");
+    fn state(&self) -> (&Arc<Mutex<f32>>, ScrollAnchor, &Arc<Mutex<ScrollMotion>>) {
         match self {
-            Scroll::Vertical(_, _, _, _, _, m, a) => {
-                let mut guard = m.lock().unwrap();
-                match a {
-                    ScrollAnchor::Start => *guard += delta,
-                    ScrollAnchor::End => *guard -= delta,
-                }
-            },
-            Scroll::Horizontal(_, _, _, _, _, m, a) => {
-                let mut guard = m.lock().unwrap();
-                match a {
-                    ScrollAnchor::Start => *guard += delta,
-                    ScrollAnchor::End => *guard -= delta,
-                }
-            }
+            Scroll::Vertical(_, _, _, _, _, v, a, m) |
+            Scroll::Horizontal(_, _, _, _, _, v, a, m) => (v, *a, m)
         }
     }
 
-    pub fn set_scroll(&mut self, val: f32) { 
-        match self {
-            Scroll::Vertical(_, _, _, _, _, m, _) =>  *m.lock().unwrap() = val,
-            Scroll::Horizontal(_, _, _, _, _, m, _) => *m.lock().unwrap() = val,
+    pub fn set_physics(&mut self, physics: ScrollPhysics) {
+        self.state().2.lock().unwrap().set_physics(physics);
+    }
+
+    pub fn physics(&self) -> ScrollPhysics {
+        self.state().2.lock().unwrap().physics
+    }
+
+    pub fn adjust_scroll(&mut self, delta: f32) { 
+        let (value, anchor, motion) = self.state();
+        let delta = match anchor {
+            ScrollAnchor::Start => delta,
+            ScrollAnchor::End => -delta,
         };
+        let mut guard = value.lock().unwrap();
+        *guard = motion.lock().unwrap().input(*guard, delta);
+    }
+
+    pub fn set_scroll(&mut self, val: f32) { 
+        let (value, _, motion) = self.state();
+        let mut guard = value.lock().unwrap();
+        *guard = motion.lock().unwrap().move_to(*guard, val, false);
+    }
+
+    /// Marks the start of a drag. Momentum and snapping wait until [`release`](Scroll::release).
+    pub fn hold(&mut self) {
+        self.state().2.lock().unwrap().hold();
+    }
+
+    /// Marks the end of a drag, letting the scroll coast with the release velocity.
+    pub fn release(&mut self) {
+        self.state().2.lock().unwrap().release();
+    }
+
+    /// Advances momentum, overscroll bounce and snapping. Call this on every `TickEvent`.
+    pub fn tick(&mut self) {
+        let (value, _, motion) = self.state();
+        let mut guard = value.lock().unwrap();
+        *guard = motion.lock().unwrap().tick(*guard);
+    }
+
+    /// Scrolls so an item at `position` (relative to the start of the viewport) with the given `size`
+    /// is placed in the viewport according to `align`.
+    pub fn scroll_into_view(&mut self, position: f32, size: f32, align: Offset, animated: bool) {
+        let (value, anchor, motion) = self.state();
+        let mut guard = value.lock().unwrap();
+        let mut motion = motion.lock().unwrap();
+        let target = motion.scroll_target(*guard, position, size, align, anchor);
+        *guard = motion.move_to(*guard, target, animated);
+    }
+
+    /// Returns the distance from the start of the content to the start of the viewport.
+    pub fn scroll_offset(&self) -> f32 {
+        let (value, anchor, motion) = self.state();
+        let max = motion.lock().unwrap().max;
+        let value = value.lock().unwrap().clamp(0.0, max);
+        match anchor {
+            ScrollAnchor::Start => value,
+            ScrollAnchor::End => max - value,
+        }
+    }
+
+    /// Returns the largest possible [`scroll_offset`](Scroll::scroll_offset) from the last layout.
+    pub fn max_offset(&self) -> f32 {
+        self.state().2.lock().unwrap().max
+    }
+
+    pub(crate) fn position(&self) -> ScrollPosition {
+        let (value, anchor, motion) = self.state();
+        ScrollPosition(value.clone(), anchor, motion.clone())
+    }
+
+    /// Returns `true` while the scroll is coasting or settling.
+    pub fn is_moving(&self) -> bool {
+        self.state().2.lock().unwrap().is_moving()
     }
 
     pub fn offset(&mut self) -> &mut Offset { 
         match self {
-            Scroll::Vertical(_, o, _, _, _, _, _) => o,
-            Scroll::Horizontal(o, _, _, _, _, _, _) => o,
+            Scroll::Vertical(_, o, _, _, _, _, _, _) => o,
+            Scroll::Horizontal(o, _, _, _, _, _, _, _) => o,
         }
     }
 }
@@ -479,8 +1118,8 @@ impl Layout for Scroll {
         ).unzip();
 
         let (width, height, padding) = match &self {
-            Scroll::Vertical(_, _, s_x, s_y, padd, _, _) |
-            Scroll::Horizontal(_, _, s_x, s_y, padd, _, _) => {
+            Scroll::Vertical(_, _, s_x, s_y, padd, _, _, _) |
+            Scroll::Horizontal(_, _, s_x, s_y, padd, _, _, _) => {
                 let width = s_x.get(widths, Size::max);
                 let height = s_y.get(heights, Size::max);
                 (width, height, padd)
@@ -492,38 +1131,44 @@ impl Layout for Scroll {
 
     fn build(&self, _ctx: &mut Context, scroll_size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
         match &self {
-            Scroll::Vertical(x_off, y_off, _, _, padd, val, anch) => {
+            Scroll::Vertical(x_off, y_off, _, _, padd, val, anch, motion) => {
                 let scroll_size = padd.adjust_size(scroll_size);
-                let children_height: f32 = children.iter().map(|i| i.min_height()).sum();
+                let heights = children.iter().map(|i| i.min_height()).collect::<Vec<_>>();
+                let children_height: f32 = heights.iter().sum();
                 let max_scroll = (children_height - scroll_size.1).max(0.0);
 
                 let mut scroll_val = val.lock().unwrap();
-                *scroll_val = scroll_val.clamp(0.0, max_scroll);
+                *scroll_val = motion.lock().unwrap().layout(*scroll_val, max_scroll, scroll_size.1, &heights, *anch);
 
+                let mut position = 0.0;
                 children.into_iter().map(|i| {
                     let size = i.get(scroll_size);
                     let y_offset = match anch {
-                        ScrollAnchor::Start => y_off.get(scroll_size.1, size.1)-*scroll_val,
-                        ScrollAnchor::End => scroll_size.1 - children_height + *scroll_val,
+                        ScrollAnchor::Start => y_off.get(scroll_size.1, size.1) + position - *scroll_val,
+                        ScrollAnchor::End => scroll_size.1 - children_height + position + *scroll_val,
                     };
+                    position += i.min_height();
                     let offset = (x_off.get(scroll_size.0, size.0), y_offset);
                     Area {offset: padd.adjust_offset(offset), size }
                 }).collect()
             }
-            Scroll::Horizontal(x_off, y_off, _, _, padd, val, anch) => {
+            Scroll::Horizontal(x_off, y_off, _, _, padd, val, anch, motion) => {
                 let scroll_size = padd.adjust_size(scroll_size);
-                let children_width: f32 = children.iter().map(|i| i.min_width()).sum();
+                let widths = children.iter().map(|i| i.min_width()).collect::<Vec<_>>();
+                let children_width: f32 = widths.iter().sum();
                 let max_scroll = (children_width - scroll_size.0).max(0.0);
 
                 let mut scroll_val = val.lock().unwrap();
-                *scroll_val = scroll_val.clamp(0.0, max_scroll);
+                *scroll_val = motion.lock().unwrap().layout(*scroll_val, max_scroll, scroll_size.0, &widths, *anch);
 
+                let mut position = 0.0;
                 children.into_iter().map(|i| {
                     let size = i.get(scroll_size);
                     let x_offset = match anch {
-                        ScrollAnchor::Start => x_off.get(scroll_size.0, size.0) - *scroll_val,
-                        ScrollAnchor::End => scroll_size.0 - children_width + *scroll_val,
+                        ScrollAnchor::Start => x_off.get(scroll_size.0, size.0) + position - *scroll_val,
+                        ScrollAnchor::End => scroll_size.0 - children_width + position + *scroll_val,
                     };
+                    position += i.min_width();
                     let offset = (x_offset, y_off.get(scroll_size.1, size.1));
                     Area {offset: padd.adjust_offset(offset), size }
                 }).collect()
@@ -596,3 +1241,42 @@ impl<L: Drawable + 'static, R: Drawable + 'static> EitherOr<L, R> {
     pub fn left(&mut self) -> &mut L { self.1.inner() }
     pub fn right(&mut self) -> &mut R { self.2.inner() }
 }
+
+/// A container that holds any number of drawables but displays only one at a time, like an N-way [`EitherOr`].
+///
+/// ```rust
+/// let mut panes = OneOf::new(vec![Box::new(sent), Box::new(received), Box::new(pending)], 0);
+/// panes.display(2);
+/// ```
+#[derive(Debug, Component)]
+pub struct OneOf(Stack, Option<Box<dyn Drawable>>, #[skip] Vec<Option<Box<dyn Drawable>>>, #[skip] usize);
+
+impl OnEvent for OneOf {}
+
+impl OneOf {
+    pub fn new(items: Vec<Box<dyn Drawable>>, index: usize) -> Self {
+        let mut items: Vec<_> = items.into_iter().map(Some).collect();
+        let index = index.min(items.len().saturating_sub(1));
+        let shown = items.get_mut(index).and_then(|item| item.take());
+        OneOf(Stack::default(), shown, items, index)
+    }
+
+    /// Shows the item at `index` and hides the rest.
+    pub fn display(&mut self, index: usize) {
+        if index == self.3 || index >= self.2.len() { return; }
+        self.2[self.3] = self.1.take();
+        self.1 = self.2[index].take();
+        self.3 = index;
+    }
+
+    /// Returns the index of the shown item.
+    pub fn index(&self) -> usize { self.3 }
+    pub fn len(&self) -> usize { self.2.len() }
+    pub fn is_empty(&self) -> bool { self.2.is_empty() }
+
+    /// Returns the item at `index` if it is a `T`.
+    pub fn get<T: std::any::Any>(&mut self, index: usize) -> Option<&mut T> {
+        let item = if index == self.3 { self.1.as_mut() } else { self.2.get_mut(index)?.as_mut() };
+        item?.as_any_mut().downcast_mut::<T>()
+    }
+}
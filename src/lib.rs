@@ -50,22 +50,39 @@
 mod events;
 pub use events::{
     NavigateEvent,
+    NavigationEvent,
+    PageSlot,
+    Slot,
+    PresentModalEvent,
+    DismissModalEvent,
+    PresentPopoverEvent,
+    DismissPopoverEvent,
+    ShowTooltipEvent,
+    HideTooltipEvent,
+    AnchorEvent,
+    ToastEvent,
+    DismissToastEvent,
     KeyboardActiveEvent,
     ClearActiveInput,
     SetActiveInput,
+    SetInputValue,
     TextInputSelect,
     ListItemSelect,
     NavigatorSelect,
+    TabSelect,
     NavigatorEvent,
     SearchEvent,
     InputEditedEvent,
     AdjustScrollEvent,
+    ScrollToEvent,
+    ScrollChangedEvent,
     QRCodeScannedEvent,
     AttachmentEvent,
+    MessageComposedEvent,
 };
 
 mod config;
-pub use config::{IS_MOBILE, IS_WEB};
+pub use config::{IS_MOBILE, IS_WEB, Breakpoints, InterfaceMode, breakpoints, set_breakpoints, interface_mode, reduce_motion, set_reduce_motion};
 
 mod layout;
 pub use layout::{
@@ -74,13 +91,22 @@ pub use layout::{
     Padding, 
     Column, 
     Row, 
+    Flex,
+    FlexItem,
+    Justify,
     Wrap, 
+    Grid,
+    GridTrack,
     Scroll, 
     Stack, 
     ScrollAnchor, 
+    ScrollPhysics,
+    ScrollSnap,
+    ScrollMotion,
     Bin, 
     Opt, 
     EitherOr,
+    OneOf,
     UniformExpand
 };
 
@@ -111,6 +137,7 @@ pub use components::{
     IconButton,
     QuickActions,
     Alert,
+    AlertStyle,
     Avatar,
     AvatarContent,
     AvatarIconStyle,
@@ -124,12 +151,54 @@ pub use components::{
     QRCodeScanner,
     Slider,
     Interface,
+    PageHost,
+    NavigatorTabs,
+    Modal,
+    ModalStyle,
+    ModalResult,
+    Popover,
+    Placement,
+    Tooltip,
+    Toast,
     Page,
     Header,
     Bumper,
     Content,
     HeaderIcon,
-    HeaderContent
+    HeaderContent,
+    LazyList,
+    SectionedList,
+    Section,
+    SectionHeader,
+    Toggle,
+    Checkbox,
+    CheckState,
+    RadioGroup,
+    SegmentedControl,
+    TabBar,
+    TabView,
+    Select,
+    ContextMenu,
+    DatePicker,
+    DateRangePicker,
+    TimePicker,
+    DateBounds,
+    AmountInput,
+    AmountUnit,
+    Passcode,
+    PasscodeMode,
+    MnemonicDisplay,
+    MnemonicInput,
+    Message,
+    MessageDirection,
+    MessageStatus,
+    MessageBubble,
+    MessageList,
+    MessageComposer,
+    Chart,
+    ProgressBar,
+    ProgressRing,
+    Spinner,
 };
 
 mod utils;
@@ -142,9 +211,11 @@ pub use utils::{
 mod pages;
 pub use pages::{
     AppPage, 
+    Transition,
     Error, 
     Splash, 
-    PelicanHome
+    PelicanHome,
+    PasscodePage
 };
 
 // mod themes;
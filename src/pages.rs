@@ -3,7 +3,7 @@ use pelican_ui::{
     Drawable, Layout, OnEvent, SizeRequest,
 };
 
-use crate::components::{Page, Content, Header, Bumper, Button};
+use crate::components::{Page, Content, Header, Bumper, Button, Passcode, ProgressBar};
 use crate::layout::{Offset, Stack};
 use crate::elements::{TextStyle, Text, AspectRatioImage};
 use crate::events::NavigateEvent;
@@ -45,6 +45,20 @@ use crate::events::NavigateEvent;
 /// })
 /// ```
 ///
+/// # Navigation History
+///
+/// Pages can also be pushed onto the navigation history with a [`NavigationEvent`](crate::NavigationEvent).
+/// The current page is kept alive, and popping returns to it with its state intact.
+///
+/// ```rust
+/// ctx.trigger_event(NavigationEvent::push(Receive::new(ctx)));
+/// ctx.trigger_event(NavigationEvent::Pop);
+/// ctx.trigger_event(NavigationEvent::pop_to::<Home>());
+/// ```
+///
+/// Escape, the browser back key and the hardware back button pop the current page, unless
+/// [`AppPage::can_go_back`] returns `false`.
+///
 /// # Navigator Bar
 ///
 /// When creating an [`Interface`], you can optionally pass in navigatable pages to the navigation bar.
@@ -57,6 +71,54 @@ pub trait AppPage: Drawable + std::fmt::Debug + 'static {
 
     /// Returns whether a navigation bar is visible (mobile specific).
     fn has_nav(&self) -> bool {true}
+
+    /// Returns the transition used when navigating to this page. Going back from this page plays it in reverse.
+    /// Pages are swapped without an animation unless they opt in.
+    fn transition(&self) -> Transition {Transition::None}
+
+    /// Returns whether the back keys may leave this page. Pages like a lock screen return `false`
+    /// and move on by themselves with a [`NavigationEvent`](crate::NavigationEvent).
+    fn can_go_back(&self) -> bool {true}
+}
+
+/// The animation played when the interface changes pages.
+///
+/// When [`reduce_motion`](crate::reduce_motion) is set, [`Slide`](Transition::Slide) and
+/// [`Modal`](Transition::Modal) fall back to [`Fade`](Transition::Fade).
+///
+/// A [`NavigateEvent`] hands the outgoing page to [`AppPage::navigate`], so there is nothing left to
+/// animate out and the new page is shown without a transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Transition {
+    /// The page is swapped instantly.
+    #[default]
+    None,
+    /// The new page slides in from the right, going back slides it out again.
+    Slide,
+    /// The old page fades out to the background and the new page fades in.
+    Fade,
+    /// The new page slides up from the bottom over the old page, going back slides it down.
+    Modal,
+}
+
+impl Transition {
+    /// Returns the transition to play, taking [`reduce_motion`](crate::reduce_motion) into account.
+    pub fn resolve(self) -> Self {
+        match self {
+            Transition::Slide | Transition::Modal if crate::config::reduce_motion() => Transition::Fade,
+            transition => transition,
+        }
+    }
+
+    /// Returns the length of the animation in seconds.
+    pub fn duration(&self) -> f32 {
+        match self {
+            Transition::None => 0.0,
+            Transition::Slide => 0.3,
+            Transition::Fade => 0.25,
+            Transition::Modal => 0.35,
+        }
+    }
 }
 
 
@@ -90,6 +152,13 @@ impl Error {
 }
 
 /// Splash page shown when the app first launches.
+///
+/// Call [`Splash::set_progress`] to show a [`ProgressBar`] under the wordmark while the app loads.
+///
+/// ```rust
+/// let mut splash = Splash::new(ctx);
+/// splash.set_progress(ctx, 0.4);
+/// ```
 #[derive(Debug, Component)]
 pub struct Splash(Stack, Page);
 impl OnEvent for Splash {}
@@ -105,6 +174,20 @@ impl Splash {
 
         Splash(Stack::default(), Page::new(None, content, None))
     }
+
+    /// Shows how far the app has loaded, from `0.0` to `1.0`.
+    pub fn set_progress(&mut self, ctx: &mut Context, progress: f32) {
+        let content = self.1.content();
+        match content.find::<ProgressBar>() {
+            Some(bar) => bar.set_progress(progress),
+            None => content.items().push(Box::new(ProgressBar::new(ctx, progress).width(162.0))),
+        }
+    }
+
+    /// Returns the load progress, if it is shown.
+    pub fn progress(&mut self) -> Option<f32> {
+        self.1.content().find::<ProgressBar>().map(|bar| bar.progress())
+    }
 }
 
 /// Example landing page for Pelican UI.
@@ -131,4 +214,39 @@ impl PelicanHome {
         let content = Content::new(ctx, Offset::Center, vec![Box::new(illustration), Box::new(title), Box::new(text)]);
         PelicanHome(Stack::default(), Page::new(None, content, None))
     }
-}
\ No newline at end of file
+}
+
+/// Page asking for a [`Passcode`], e.g. to unlock the app or to set a new passcode.
+///
+/// The page has no way back on its own, and the back keys don't leave it. Move on from the passcode's callback with a
+/// [`NavigationEvent`](crate::NavigationEvent) or [`NavigateEvent`].
+///
+/// ```rust
+/// let passcode = Passcode::new(ctx, 6, PasscodeMode::Create, |ctx: &mut Context, digits: &[u8]| {
+///     save_passcode(digits);
+///     ctx.trigger_event(NavigationEvent::Pop);
+/// });
+/// let page = PasscodePage::new(ctx, "Security", passcode);
+/// ```
+#[derive(Debug, Component)]
+pub struct PasscodePage(Stack, Page);
+impl OnEvent for PasscodePage {}
+
+impl AppPage for PasscodePage {
+    fn has_nav(&self) -> bool { false }
+    fn can_go_back(&self) -> bool { false }
+    fn navigate(self: Box<Self>, _ctx: &mut Context, _index: usize) -> Result<Box<dyn AppPage>, Box<dyn AppPage>> { Err(self) }
+}
+
+impl PasscodePage {
+    pub fn new(ctx: &mut Context, title: &str, passcode: Passcode) -> Self {
+        let header = Header::stack(ctx, None, title, None);
+        let content = Content::new(ctx, Offset::Center, vec![Box::new(passcode)]);
+        PasscodePage(Stack::default(), Page::new(Some(header), content, None))
+    }
+
+    /// Returns the [`Passcode`] on this page.
+    pub fn passcode(&mut self) -> &mut Passcode {
+        self.1.content().find::<Passcode>().unwrap()
+    }
+}